@@ -0,0 +1,282 @@
+use std::fmt;
+
+use super::max_coverage::{Budget, MaxCoverage};
+use super::set::{Set, SetError};
+
+// Holgura para que el redondeo de la fracción no suba k: 0.07 * 100 da 7.000000000000001
+const FRACTION_TOLERANCE: f64 = 1e-9;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PartialTarget {
+    Elements(usize),
+    Fraction(f64),
+}
+
+impl PartialTarget {
+    pub fn resolve(&self, set : &Set) -> Result<CoverMode, SetError> {
+        let size_set = set.get_size_set();
+        let k = match *self {
+            PartialTarget::Elements(k) => k,
+            PartialTarget::Fraction(fraction) => (fraction * size_set as f64 - FRACTION_TOLERANCE).ceil() as usize,
+        };
+
+        if k > size_set {
+            return Err(SetError::InsufficientCoverage { required : k, coverable : size_set });
+        }
+        Ok(CoverMode::Partial(k))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ModeRequest {
+    Full,
+    Partial(PartialTarget),
+    MultiCover,
+    Partition,
+    MaxCoverage(Budget),
+}
+
+impl ModeRequest {
+    pub fn resolve(&self, set : &Set) -> Result<CoverMode, SetError> {
+        match self {
+            ModeRequest::Full => Ok(CoverMode::Full),
+            ModeRequest::Partial(target) => target.resolve(set),
+            ModeRequest::MultiCover => Ok(CoverMode::MultiCover),
+            ModeRequest::Partition => Ok(CoverMode::Partition),
+            ModeRequest::MaxCoverage(budget) => Ok(CoverMode::MaxCoverage(*budget)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum CoverMode {
+    Full,
+    Partial(usize),
+    MultiCover,
+    Partition,
+    MaxCoverage(Budget),
+}
+
+impl CoverMode {
+    pub fn is_feasible(&self, subsets : &[usize], set : &Set) -> bool {
+        match self {
+            CoverMode::Full => set.is_cover_valid(subsets),
+            CoverMode::Partial(k) => set.is_partial_cover_valid(subsets, *k),
+            CoverMode::MultiCover => set.is_multicover_valid(subsets),
+            CoverMode::Partition => set.is_partition_valid(subsets),
+            CoverMode::MaxCoverage(budget) => budget.is_within(subsets, set),
+        }
+    }
+
+    // Igual que is_feasible a partir de las multiplicidades de la familia (coverage_counts)
+    pub fn is_feasible_with_counts(&self, subsets : &[usize], counts : &[usize], set : &Set) -> bool {
+        match self {
+            CoverMode::Full => counts.iter().all(|&count| count > 0),
+            CoverMode::Partial(k) => counts.iter().filter(|&&count| count > 0).count() >= *k,
+            CoverMode::MultiCover => counts.iter().enumerate().all(|(index, &count)| count >= set.get_demand(index)),
+            CoverMode::Partition => counts.iter().all(|&count| count == 1),
+            CoverMode::MaxCoverage(budget) => budget.is_within(subsets, set),
+        }
+    }
+
+    // Unidades (ponderadas por el peso de cada elemento) que faltan para que la cobertura sea
+    // factible; es lo que penaliza el costo. En cobertura parcial se cuentan elementos.
+    pub fn shortfall(&self, subsets : &[usize], set : &Set) -> Result<f64, SetError> {
+        match self {
+            CoverMode::Full => set.uncovered_weight(subsets),
+            CoverMode::Partial(k) => {
+                let covered = set.union_size(subsets)?;
+                Ok(k.saturating_sub(covered) as f64)
+            }
+            CoverMode::MultiCover => {
+                let counts = set.coverage_counts(subsets)?;
+                Ok(counts.iter()
+                    .enumerate()
+                    .map(|(index, &count)| set.get_demand(index).saturating_sub(count) as f64 * set.get_element_weight(index))
+                    .sum())
+            }
+            CoverMode::Partition => {
+                let counts = set.coverage_counts(subsets)?;
+                Ok(counts.iter()
+                    .enumerate()
+                    .map(|(index, &count)| count.abs_diff(1) as f64 * set.get_element_weight(index))
+                    .sum())
+            }
+            CoverMode::MaxCoverage(budget) => MaxCoverage::new(*budget).penalty(subsets, set),
+        }
+    }
+
+    // Cuánto reduce el faltante agregar cada subconjunto a la familia (negativo si lo empeora),
+    // calculado a partir de las multiplicidades sin reevaluar shortfall por candidato.
+    // Los subconjuntos que ya están en la familia tienen ganancia 0.
+    pub fn marginal_gains(&self, subsets : &[usize], set : &Set) -> Result<Vec<f64>, SetError> {
+        let counts = set.coverage_counts(subsets)?;
+        let mut gains: Vec<f64> = Vec::new();
+        self.marginal_gains_into(subsets, &counts, set, &mut gains);
+        Ok(gains)
+    }
+
+    // Igual que marginal_gains a partir de las multiplicidades de la familia, escribiendo en un
+    // buffer que los solvers reutilizan entre iteraciones
+    pub fn marginal_gains_into(&self, subsets : &[usize], counts : &[usize], set : &Set, gains : &mut Vec<f64>) {
+        let remaining = match self {
+            CoverMode::Partial(k) => k.saturating_sub(counts.iter().filter(|&&count| count > 0).count()),
+            _ => 0,
+        };
+        let spent = match self {
+            CoverMode::MaxCoverage(_) => set.subsets_cost(subsets),
+            _ => 0.0,
+        };
+
+        gains.clear();
+        gains.extend((0..set.get_size_subsets()).map(|candidate| {
+            let elements = set.elements_of(candidate);
+            let weight = |element : usize| set.get_element_weight(element);
+            match self {
+                CoverMode::Full => elements.iter()
+                    .filter(|&&element| counts[element] == 0)
+                    .map(|&element| weight(element))
+                    .sum(),
+                CoverMode::Partial(_) => {
+                    let new = elements.iter().filter(|&&element| counts[element] == 0).count();
+                    new.min(remaining) as f64
+                }
+                CoverMode::MultiCover => elements.iter()
+                    .filter(|&&element| counts[element] < set.get_demand(element))
+                    .map(|&element| weight(element))
+                    .sum(),
+                CoverMode::Partition => elements.iter()
+                    .map(|&element| if counts[element] == 0 { weight(element) } else { -weight(element) })
+                    .sum(),
+                CoverMode::MaxCoverage(budget) => {
+                    if !budget.admits(subsets.len() + 1, spent + set.get_subset_cost(candidate)) {
+                        return f64::NEG_INFINITY;
+                    }
+                    elements.iter()
+                        .filter(|&&element| counts[element] == 0)
+                        .map(|&element| weight(element))
+                        .sum()
+                }
+            }
+        }));
+        for &index in subsets {
+            gains[index] = 0.0;
+        }
+    }
+
+    pub fn check_feasible(&self, set : &Set) -> Result<(), SetError> {
+        match self {
+            CoverMode::Full => set.check_coverable(),
+            CoverMode::Partial(k) => {
+                let coverable = set.get_size_set() - set.uncoverable_elements().len();
+                if coverable < *k {
+                    return Err(SetError::InsufficientCoverage { required : *k, coverable });
+                }
+                Ok(())
+            }
+            CoverMode::MultiCover => set.check_multicoverable(),
+            CoverMode::Partition => set.check_coverable(),
+            CoverMode::MaxCoverage(_) => Ok(()),
+        }
+    }
+
+    // Lleva una solución hacia la factibilidad del modo: en partición primero elimina los
+    // subconjuntos que más sobrecubren y después agrega subconjuntos disjuntos de lo cubierto;
+    // en los demás modos agrega el subconjunto que más reduce el faltante.
+    pub fn repair(&self, subsets : &[usize], set : &Set) -> Vec<usize> {
+        let mut repaired: Vec<usize> = subsets.iter()
+            .copied()
+            .filter(|&index| index < set.get_size_subsets())
+            .collect();
+        repaired.sort_unstable();
+        repaired.dedup();
+
+        match self {
+            CoverMode::Partition => return CoverMode::repair_partition(repaired, set),
+            CoverMode::MaxCoverage(budget) => return MaxCoverage::new(*budget).repair(&repaired, set),
+            _ => {}
+        }
+
+        // Las multiplicidades se actualizan con cada subconjunto agregado y la reducción del
+        // faltante de cada candidato sale de marginal_gains_into, igual que en el greedy
+        if let Ok(mut counts) = set.coverage_counts(&repaired) {
+            let mut gains: Vec<f64> = Vec::new();
+            loop {
+                self.marginal_gains_into(&repaired, &counts, set, &mut gains);
+                let mut best: Option<(usize, f64)> = None;
+                for (candidate, &gain) in gains.iter().enumerate() {
+                    if gain > 0.0 && best.is_none_or(|(_, value)| gain > value) {
+                        best = Some((candidate, gain));
+                    }
+                }
+
+                match best {
+                    Some((candidate, _)) => {
+                        repaired.push(candidate);
+                        set.add_coverage(candidate, &mut counts);
+                    }
+                    None => break,
+                }
+            }
+        }
+
+        repaired.sort_unstable();
+        repaired
+    }
+
+    fn repair_partition(mut repaired : Vec<usize>, set : &Set) -> Vec<usize> {
+        // Se elimina el subconjunto con más elementos sobrecubiertos; en empate el que cubre
+        // menos elementos en exclusiva y después el de mayor índice.
+        while let Ok(counts) = set.coverage_counts(&repaired) {
+            let worst = repaired.iter()
+                .map(|&index| {
+                    let elements = set.elements_of(index);
+                    let over = elements.iter().filter(|&&element| counts[element] > 1).count();
+                    let exclusive = elements.len() - over;
+                    (over, std::cmp::Reverse(exclusive), index)
+                })
+                .filter(|&(over, _, _)| over > 0)
+                .max();
+
+            match worst {
+                Some((_, _, index)) => repaired.retain(|&i| i != index),
+                None => break,
+            }
+        }
+
+        let mut covered: Vec<bool> = match set.coverage_counts(&repaired) {
+            Ok(counts) => counts.iter().map(|&count| count > 0).collect(),
+            Err(_) => vec![false; set.get_size_set()],
+        };
+
+        let mut candidates: Vec<(usize, &[usize])> = (0..set.get_size_subsets())
+            .filter(|index| !repaired.contains(index))
+            .map(|index| (index, set.elements_of(index)))
+            .collect();
+        candidates.sort_by(|a, b| b.1.len().cmp(&a.1.len()).then(a.0.cmp(&b.0)));
+
+        for (index, elements) in candidates {
+            if !elements.is_empty() && elements.iter().all(|&element| !covered[element]) {
+                for &element in elements.iter() {
+                    covered[element] = true;
+                }
+                repaired.push(index);
+            }
+        }
+
+        repaired.sort_unstable();
+        repaired
+    }
+}
+
+impl fmt::Display for CoverMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CoverMode::Full => write!(f, "cobertura completa"),
+            CoverMode::Partial(k) => write!(f, "cobertura parcial (al menos {} elementos)", k),
+            CoverMode::MultiCover => write!(f, "multicobertura (demanda por elemento)"),
+            CoverMode::Partition => write!(f, "partición (cada elemento exactamente una vez)"),
+            CoverMode::MaxCoverage(budget) => write!(f, "cobertura máxima con presupuesto ({})", budget),
+        }
+    }
+}
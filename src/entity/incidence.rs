@@ -0,0 +1,78 @@
+// Estructura dispersa comprimida: la fila i son los índices indices[offsets[i]..offsets[i + 1]],
+// en orden creciente. Set guarda dos: por subconjunto (CSR) y por elemento (CSC).
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Incidence {
+    pub offsets : Vec<usize>,
+    pub indices : Vec<usize>
+}
+
+impl Incidence {
+    // Filas a partir de listas de índices en cualquier orden y con repetidos; cada fila queda
+    // ordenada y sin repetidos
+    pub fn from_rows<'a>(rows : impl Iterator<Item = &'a [usize]>) -> Self {
+        let mut offsets: Vec<usize> = vec![0];
+        let mut indices: Vec<usize> = Vec::new();
+        for row in rows {
+            let start = indices.len();
+            indices.extend_from_slice(row);
+            sort_unique_tail(&mut indices, start);
+            offsets.push(indices.len());
+        }
+        Incidence { offsets, indices }
+    }
+
+    // La vista transpuesta, con columns filas
+    pub fn transpose(&self, columns : usize) -> Self {
+        let mut counts: Vec<usize> = vec![0; columns + 1];
+        for &column in self.indices.iter() {
+            counts[column + 1] += 1;
+        }
+        for column in 0..columns {
+            counts[column + 1] += counts[column];
+        }
+
+        let offsets = counts.clone();
+        let mut next = counts;
+        let mut indices: Vec<usize> = vec![0; self.indices.len()];
+        for row in 0..self.len() {
+            for &column in self.row(row) {
+                indices[next[column]] = row;
+                next[column] += 1;
+            }
+        }
+        Incidence { offsets, indices }
+    }
+
+    pub fn len(&self) -> usize {
+        self.offsets.len().saturating_sub(1)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn row(&self, row : usize) -> &[usize] {
+        &self.indices[self.offsets[row]..self.offsets[row + 1]]
+    }
+
+    pub fn row_len(&self, row : usize) -> usize {
+        self.offsets[row + 1] - self.offsets[row]
+    }
+
+    pub fn non_zero(&self) -> usize {
+        self.indices.len()
+    }
+}
+
+// Ordena y quita repetidos de indices[start..] en su lugar, sin tocar lo anterior
+pub(crate) fn sort_unique_tail(indices : &mut Vec<usize>, start : usize) {
+    indices[start..].sort_unstable();
+    let mut unique = start;
+    for position in start..indices.len() {
+        if unique == start || indices[unique - 1] != indices[position] {
+            indices[unique] = indices[position];
+            unique += 1;
+        }
+    }
+    indices.truncate(unique);
+}
@@ -0,0 +1,148 @@
+use std::borrow::Cow;
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+
+use super::set::ElementOrder;
+use crate::utils::natural_order::natural_cmp;
+
+// Id entero de una etiqueta si es un entero canónico (sin signo ni ceros a la izquierda), de modo
+// que convertirla de vuelta a texto devuelve la misma etiqueta
+pub fn parse_canonical_id(label : &str) -> Option<u32> {
+    let canonical = !label.is_empty()
+        && label.bytes().all(|byte| byte.is_ascii_digit())
+        && (label == "0" || !label.starts_with('0'));
+    if canonical { label.parse().ok() } else { None }
+}
+
+// Etiquetas internadas de los elementos: el resto del Set trabaja solo con índices densos
+// 0..|X| y las etiquetas se resuelven al imprimir o al leer directivas por nombre.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Labels {
+    // Etiquetas arbitrarias: nombre -> índice e índice -> nombre
+    Named { index_of : BTreeMap<String, usize>, names : Vec<String> },
+    // Etiquetas enteras: id -> índice e índice -> id, sin cadenas
+    Numeric { index_of : BTreeMap<u32, usize>, ids : Vec<u32> },
+}
+
+impl Labels {
+    // Asigna índices en orden de primera aparición
+    pub fn named<'a>(labels : impl IntoIterator<Item = &'a String>) -> Self {
+        let mut index_of: BTreeMap<String, usize> = BTreeMap::new();
+        let mut names: Vec<String> = Vec::new();
+        for label in labels {
+            if !index_of.contains_key(label) {
+                index_of.insert(label.clone(), names.len());
+                names.push(label.clone());
+            }
+        }
+        Labels::Named { index_of, names }
+    }
+
+    pub fn numeric(labels : impl IntoIterator<Item = u32>) -> Self {
+        let mut index_of: BTreeMap<u32, usize> = BTreeMap::new();
+        let mut ids: Vec<u32> = Vec::new();
+        for label in labels {
+            if let std::collections::btree_map::Entry::Vacant(entry) = index_of.entry(label) {
+                entry.insert(ids.len());
+                ids.push(label);
+            }
+        }
+        Labels::Numeric { index_of, ids }
+    }
+
+    // Índice de la etiqueta, agregándola si es nueva. Las etiquetas numéricas pasan a ser de
+    // texto en cuanto aparece una que no es un entero canónico.
+    pub fn intern(&mut self, label : &str) -> usize {
+        if let Labels::Numeric { index_of, ids } = self {
+            match parse_canonical_id(label) {
+                Some(id) => {
+                    let next = ids.len();
+                    let index = *index_of.entry(id).or_insert(next);
+                    if index == next {
+                        ids.push(id);
+                    }
+                    return index;
+                }
+                None => *self = Labels::named(self.to_strings().iter()),
+            }
+        }
+
+        match self {
+            Labels::Named { index_of, names } => {
+                if let Some(&index) = index_of.get(label) {
+                    return index;
+                }
+                index_of.insert(label.to_string(), names.len());
+                names.push(label.to_string());
+                names.len() - 1
+            }
+            Labels::Numeric { .. } => unreachable!("las etiquetas numéricas se convirtieron arriba"),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        match self {
+            Labels::Named { names, .. } => names.len(),
+            Labels::Numeric { ids, .. } => ids.len(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn is_numeric(&self) -> bool {
+        matches!(self, Labels::Numeric { .. })
+    }
+
+    pub fn index_of(&self, label : &str) -> Option<usize> {
+        match self {
+            Labels::Named { index_of, .. } => index_of.get(label).copied(),
+            Labels::Numeric { index_of, .. } => parse_canonical_id(label)
+                .and_then(|id| index_of.get(&id).copied()),
+        }
+    }
+
+    pub fn label(&self, index : usize) -> Option<Cow<'_, str>> {
+        match self {
+            Labels::Named { names, .. } => names.get(index).map(|name| Cow::Borrowed(name.as_str())),
+            Labels::Numeric { ids, .. } => ids.get(index).map(|id| Cow::Owned(id.to_string())),
+        }
+    }
+
+    // Todas las etiquetas, en el orden de sus índices
+    pub fn to_strings(&self) -> Vec<String> {
+        (0..self.len())
+            .filter_map(|index| self.label(index))
+            .map(|label| label.into_owned())
+            .collect()
+    }
+
+    pub fn compare(&self, a : usize, b : usize, order : ElementOrder) -> Ordering {
+        match (self, order) {
+            (_, ElementOrder::FirstSeen) => a.cmp(&b),
+            (Labels::Named { names, .. }, ElementOrder::Lexicographic) => names[a].cmp(&names[b]),
+            (Labels::Named { names, .. }, ElementOrder::Natural) => natural_cmp(&names[a], &names[b]),
+            (Labels::Numeric { ids, .. }, ElementOrder::Lexicographic) => ids[a].to_string().cmp(&ids[b].to_string()),
+            (Labels::Numeric { ids, .. }, ElementOrder::Natural) => ids[a].cmp(&ids[b]),
+        }
+    }
+
+    // El nuevo índice i corresponde al índice anterior old_indices[i]
+    pub fn permute(&mut self, old_indices : &[usize]) {
+        match self {
+            Labels::Named { index_of, names } => {
+                *names = old_indices.iter().map(|&index| names[index].clone()).collect();
+                for (new_index, name) in names.iter().enumerate() {
+                    index_of.insert(name.clone(), new_index);
+                }
+            }
+            Labels::Numeric { index_of, ids } => {
+                *ids = old_indices.iter().map(|&index| ids[index]).collect();
+                for (new_index, &id) in ids.iter().enumerate() {
+                    index_of.insert(id, new_index);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,153 @@
+use std::fmt;
+
+use super::cover_mode::CoverMode;
+use super::set::{Set, SetError};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Budget {
+    Subsets(usize),
+    Cost(f64),
+}
+
+impl Budget {
+    pub fn is_within(&self, subsets : &[usize], set : &Set) -> bool {
+        self.excess(subsets, set) <= 0.0
+    }
+
+    // Si una familia de size subconjuntos con costo total cost respeta el presupuesto
+    pub fn admits(&self, size : usize, cost : f64) -> bool {
+        match *self {
+            Budget::Subsets(limit) => size <= limit,
+            Budget::Cost(limit) => cost <= limit,
+        }
+    }
+
+    // Cuánto se excede el presupuesto (en subconjuntos o en costo); 0 si se respeta
+    pub fn excess(&self, subsets : &[usize], set : &Set) -> f64 {
+        match *self {
+            Budget::Subsets(limit) => subsets.len().saturating_sub(limit) as f64,
+            Budget::Cost(limit) => (set.subsets_cost(subsets) - limit).max(0.0),
+        }
+    }
+}
+
+impl fmt::Display for Budget {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Budget::Subsets(limit) => write!(f, "a lo más {} subconjuntos", limit),
+            Budget::Cost(limit) => write!(f, "costo total a lo más {}", limit),
+        }
+    }
+}
+
+pub struct MaxCoverage {
+    pub budget : Budget
+}
+
+impl MaxCoverage {
+    pub fn new(budget : Budget) -> Self {
+        MaxCoverage { budget }
+    }
+
+    pub fn covered_weight(&self, subsets : &[usize], set : &Set) -> f64 {
+        set.covered_weight(subsets).unwrap_or(0.0)
+    }
+
+    // Peso sin cubrir más una penalización que hace a cualquier violación del presupuesto
+    // peor que no cubrir nada; es la cantidad que minimizan las metaheurísticas.
+    pub fn penalty(&self, subsets : &[usize], set : &Set) -> Result<f64, SetError> {
+        let total = set.total_weight();
+        let uncovered = set.uncovered_weight(subsets)?;
+        let excess = self.budget.excess(subsets, set);
+        if excess > 0.0 {
+            return Ok(uncovered + total * (1.0 + excess));
+        }
+        Ok(uncovered)
+    }
+
+    // Greedy clásico: con presupuesto de cardinalidad elige el subconjunto de mayor ganancia
+    // marginal y garantiza (1 - 1/e) del óptimo. Con presupuesto de costo elige la mayor
+    // ganancia por unidad de costo y se queda con lo mejor entre ese resultado y el mejor
+    // subconjunto individual que quepa en el presupuesto.
+    pub fn greedy(&self, set : &Set) -> Vec<usize> {
+        let by_ratio = self.extend(Vec::new(), set, matches!(self.budget, Budget::Cost(_)));
+
+        if let Budget::Cost(limit) = self.budget {
+            let best_single = (0..set.get_size_subsets())
+                .filter(|&index| set.get_subset_cost(index) <= limit)
+                .map(|index| (index, set.elements_of(index).iter().map(|&element| set.get_element_weight(element)).sum::<f64>()))
+                .max_by(|a, b| a.1.total_cmp(&b.1).then(b.0.cmp(&a.0)));
+
+            if let Some((index, weight)) = best_single
+                && weight > self.covered_weight(&by_ratio, set) {
+                return vec![index];
+            }
+        }
+
+        by_ratio
+    }
+
+    // Quita los subconjuntos que menos peso aportan hasta respetar el presupuesto y después
+    // completa con el greedy mientras quepa. Lo que aporta cada uno es el peso de los elementos
+    // que solo él cubre, que se lee de las multiplicidades sin recalcular la cobertura.
+    pub fn repair(&self, subsets : &[usize], set : &Set) -> Vec<usize> {
+        let mut repaired: Vec<usize> = subsets.to_vec();
+        if let Ok(mut counts) = set.coverage_counts(&repaired) {
+            while !self.budget.is_within(&repaired, set) && !repaired.is_empty() {
+                let (position, _) = repaired.iter()
+                    .enumerate()
+                    .map(|(position, &index)| {
+                        let exclusive: f64 = set.elements_of(index).iter()
+                            .filter(|&&element| counts[element] == 1)
+                            .map(|&element| set.get_element_weight(element))
+                            .sum();
+                        (position, exclusive)
+                    })
+                    .min_by(|a, b| a.1.total_cmp(&b.1))
+                    .unwrap_or((0, 0.0));
+                set.remove_coverage(repaired.remove(position), &mut counts);
+            }
+        }
+
+        self.extend(repaired, set, matches!(self.budget, Budget::Cost(_)))
+    }
+
+    // Agrega el subconjunto de mayor ganancia (o ganancia por unidad de costo) que quepa en el
+    // presupuesto, con las multiplicidades y las ganancias en buffers que se reutilizan
+    fn extend(&self, mut selected : Vec<usize>, set : &Set, per_cost : bool) -> Vec<usize> {
+        let mode = CoverMode::MaxCoverage(self.budget);
+        let mut counts: Vec<usize> = Vec::new();
+        let mut gains: Vec<f64> = Vec::new();
+
+        if set.coverage_counts_into(&selected, &mut counts).is_ok() {
+            loop {
+                mode.marginal_gains_into(&selected, &counts, set, &mut gains);
+                let mut best: Option<(usize, f64)> = None;
+                for (candidate, &gain) in gains.iter().enumerate() {
+                    if gain <= 0.0 {
+                        continue;
+                    }
+                    let score = if per_cost {
+                        gain / set.get_subset_cost(candidate).max(f64::EPSILON)
+                    } else {
+                        gain
+                    };
+                    if best.is_none_or(|(_, best_score)| score > best_score) {
+                        best = Some((candidate, score));
+                    }
+                }
+
+                match best {
+                    Some((candidate, _)) => {
+                        selected.push(candidate);
+                        set.add_coverage(candidate, &mut counts);
+                    }
+                    None => break,
+                }
+            }
+        }
+
+        selected.sort_unstable();
+        selected
+    }
+}
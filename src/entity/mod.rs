@@ -1,2 +1,10 @@
 pub mod set;
-pub mod subset_cover;
\ No newline at end of file
+pub mod labels;
+pub mod incidence;
+pub mod overlap;
+pub mod set_builder;
+pub mod subset_cover;
+pub mod stats;
+pub mod cover_mode;
+pub mod max_coverage;
+pub mod objective;
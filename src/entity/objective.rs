@@ -0,0 +1,199 @@
+use std::sync::{Arc, Mutex};
+
+use super::cover_mode::CoverMode;
+use super::set::Set;
+use super::subset_cover::SubsetCover;
+
+pub const OBJECTIVE_NAMES: [&str; 6] = ["default", "cardinality", "weighted", "adaptive", "squared", "lexicographic"];
+
+// Función objetivo a minimizar sobre una familia de subconjuntos. El faltante de cada modo
+// (CoverMode::shortfall) es lo que se penaliza; cada objetivo decide cómo.
+pub trait Objective : Send + Sync {
+    fn name(&self) -> &str;
+
+    fn cost(&self, subsets : &[usize], set : &Set, mode : &CoverMode) -> f64;
+
+    // Retroalimentación de los solvers después de evaluar una solución
+    fn update(&self, _feasible : bool) {}
+
+    // Estado interno que cambia con update, para guardarlo en los puntos de control
+    fn state(&self) -> Vec<f64> {
+        Vec::new()
+    }
+
+    fn restore(&self, _state : &[f64]) {}
+}
+
+pub fn objective_from_name(name : &str) -> Option<Arc<dyn Objective>> {
+    match name {
+        "default" => Some(Arc::new(DefaultObjective)),
+        "cardinality" => Some(Arc::new(CardinalityObjective)),
+        "weighted" => Some(Arc::new(WeightedObjective)),
+        "adaptive" => Some(Arc::new(AdaptivePenaltyObjective::default())),
+        "squared" => Some(Arc::new(SquaredShortfallObjective)),
+        "lexicographic" => Some(Arc::new(LexicographicObjective)),
+        _ => None,
+    }
+}
+
+// Una familia con subconjuntos que no existen en la instancia nunca es aceptable: su faltante es
+// infinito en lugar de abortar
+fn shortfall(subsets : &[usize], set : &Set, mode : &CoverMode) -> f64 {
+    mode.shortfall(subsets, set).unwrap_or(f64::INFINITY)
+}
+
+//|C| + faltante(C)max(S)|S| / |S|
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DefaultObjective;
+
+impl Objective for DefaultObjective {
+    fn name(&self) -> &str {
+        "default"
+    }
+
+    fn cost(&self, subsets : &[usize], set : &Set, mode : &CoverMode) -> f64 {
+        SubsetCover::calculate_cost_mode(subsets, set, mode)
+    }
+}
+
+//|C|, sin penalización; solo tiene sentido con soluciones que ya son factibles
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CardinalityObjective;
+
+impl Objective for CardinalityObjective {
+    fn name(&self) -> &str {
+        "cardinality"
+    }
+
+    fn cost(&self, subsets : &[usize], _set : &Set, _mode : &CoverMode) -> f64 {
+        subsets.len() as f64
+    }
+}
+
+//costo(C) + faltante(C)costo(S) / |S|, con costo(S) la suma de los costos de todos los subconjuntos
+#[derive(Debug, Clone, Copy, Default)]
+pub struct WeightedObjective;
+
+impl Objective for WeightedObjective {
+    fn name(&self) -> &str {
+        "weighted"
+    }
+
+    fn cost(&self, subsets : &[usize], set : &Set, mode : &CoverMode) -> f64 {
+        let all: Vec<usize> = (0..set.get_size_subsets()).collect();
+        let penalty = set.subsets_cost(&all).max(1.0);
+        let normalize = set.get_size_subsets() as f64;
+        (set.subsets_cost(subsets) + shortfall(subsets, set, mode) * penalty) / normalize
+    }
+}
+
+//|C| + faltante(C)λ / |S|, donde λ crece cuando los solvers evalúan soluciones infactibles
+//y decrece cuando son factibles, acotado a [min_factor, max_factor]
+#[derive(Debug)]
+pub struct AdaptivePenaltyObjective {
+    pub factor : Mutex<f64>,
+    pub growth : f64,
+    pub min_factor : f64,
+    pub max_factor : f64
+}
+
+impl AdaptivePenaltyObjective {
+    pub fn new(initial : f64, growth : f64, min_factor : f64, max_factor : f64) -> Self {
+        AdaptivePenaltyObjective {
+            factor : Mutex::new(initial.clamp(min_factor, max_factor)),
+            growth,
+            min_factor,
+            max_factor
+        }
+    }
+
+    pub fn get_factor(&self) -> f64 {
+        *self.factor.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl Default for AdaptivePenaltyObjective {
+    fn default() -> Self {
+        AdaptivePenaltyObjective::new(1.0, 1.1, 0.1, 1.0e6)
+    }
+}
+
+impl Objective for AdaptivePenaltyObjective {
+    fn name(&self) -> &str {
+        "adaptive"
+    }
+
+    fn cost(&self, subsets : &[usize], set : &Set, mode : &CoverMode) -> f64 {
+        let normalize = set.get_size_subsets() as f64;
+        (subsets.len() as f64 + shortfall(subsets, set, mode) * self.get_factor()) / normalize
+    }
+
+    fn update(&self, feasible : bool) {
+        let mut factor = self.factor.lock().unwrap_or_else(|e| e.into_inner());
+        let next = if feasible { *factor / self.growth } else { *factor * self.growth };
+        *factor = next.clamp(self.min_factor, self.max_factor);
+    }
+
+    fn state(&self) -> Vec<f64> {
+        vec![self.get_factor()]
+    }
+
+    fn restore(&self, state : &[f64]) {
+        if let Some(&saved) = state.first() {
+            *self.factor.lock().unwrap_or_else(|e| e.into_inner()) = saved.clamp(self.min_factor, self.max_factor);
+        }
+    }
+}
+
+//|C| + faltante(C)²max(S)|S| / |S|
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SquaredShortfallObjective;
+
+impl Objective for SquaredShortfallObjective {
+    fn name(&self) -> &str {
+        "squared"
+    }
+
+    fn cost(&self, subsets : &[usize], set : &Set, mode : &CoverMode) -> f64 {
+        let normalize = set.get_size_subsets() as f64;
+        let max_subset = set.get_max_subset() as f64;
+        let shortfall = shortfall(subsets, set, mode);
+        (subsets.len() as f64 + shortfall * shortfall * max_subset * normalize) / normalize
+    }
+}
+
+//faltante(C)M + costo(C): compara primero el faltante y luego el costo. Con pesos y costos de
+//a lo más MAX_DECIMALS decimales, dos faltantes distintos difieren al menos en q = 10^-decimales,
+//así que M = (costo total + 1) / q hace que cualquier diferencia de faltante pese más que el costo.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LexicographicObjective;
+
+impl Objective for LexicographicObjective {
+    fn name(&self) -> &str {
+        "lexicographic"
+    }
+
+    fn cost(&self, subsets : &[usize], set : &Set, mode : &CoverMode) -> f64 {
+        let total_cost = (0..set.get_size_subsets()).map(|subset| set.get_subset_cost(subset)).sum::<f64>();
+        let quantum = 10f64.powi(-(shortfall_decimals(set) as i32));
+        let scale = (total_cost + 1.0) / quantum;
+        shortfall(subsets, set, mode) * scale + set.subsets_cost(subsets)
+    }
+}
+
+const MAX_DECIMALS: u32 = 9;
+
+// Decimales que necesitan los pesos y los costos (estos entran al faltante por el presupuesto de
+// la cobertura máxima); las demandas y k son enteros
+fn shortfall_decimals(set : &Set) -> u32 {
+    let weights = (0..set.get_size_set()).map(|element| set.get_element_weight(element));
+    let costs = (0..set.get_size_subsets()).map(|subset| set.get_subset_cost(subset));
+    weights.chain(costs).map(decimals).max().unwrap_or(0)
+}
+
+fn decimals(value : f64) -> u32 {
+    (0..MAX_DECIMALS).find(|&places| {
+        let scaled = value * 10f64.powi(places as i32);
+        (scaled - scaled.round()).abs() <= 1e-9 * scaled.abs().max(1.0)
+    }).unwrap_or(MAX_DECIMALS)
+}
@@ -0,0 +1,93 @@
+use serde::Serialize;
+
+use super::set::{Set, SetError};
+
+const WORD_BITS: usize = u64::BITS as usize;
+
+// Análisis de solapamiento de una familia de subconjuntos. Cada subconjunto se representa
+// como un bitset sobre X y las intersecciones se cuentan con AND y popcount.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct OverlapAnalysis {
+    pub subsets : Vec<usize>,
+    // |S_i ∩ S_j| para las posiciones i, j de subsets, en orden por filas (k x k)
+    pub matrix : Vec<usize>,
+    // Cuántos subconjuntos de la familia cubren cada elemento
+    pub multiplicity : Vec<usize>,
+    // Σ multiplicidad − elementos cubiertos; en una cobertura completa es Σ cobertura − |X|
+    pub redundancy : usize
+}
+
+impl OverlapAnalysis {
+    pub fn new(subsets : &[usize], set : &Set) -> Result<Self, SetError> {
+        let multiplicity = set.coverage_counts(subsets)?;
+
+        let words = set.get_size_set().div_ceil(WORD_BITS);
+        let bitsets: Vec<Vec<u64>> = subsets.iter()
+            .map(|&index| {
+                let mut bits: Vec<u64> = vec![0; words];
+                for &element in set.elements_of(index) {
+                    bits[element / WORD_BITS] |= 1 << (element % WORD_BITS);
+                }
+                bits
+            })
+            .collect();
+
+        let size = subsets.len();
+        let mut matrix: Vec<usize> = vec![0; size * size];
+        for i in 0..size {
+            for j in i..size {
+                let shared: usize = bitsets[i].iter()
+                    .zip(bitsets[j].iter())
+                    .map(|(a, b)| (a & b).count_ones() as usize)
+                    .sum();
+                matrix[i * size + j] = shared;
+                matrix[j * size + i] = shared;
+            }
+        }
+
+        let total: usize = multiplicity.iter().sum();
+        let covered = multiplicity.iter().filter(|&&count| count > 0).count();
+
+        Ok(OverlapAnalysis {
+            subsets : subsets.to_vec(),
+            matrix,
+            multiplicity,
+            redundancy : total - covered
+        })
+    }
+
+    // Tamaño de la intersección entre las posiciones i y j de la familia
+    pub fn overlap(&self, i : usize, j : usize) -> usize {
+        self.matrix[i * self.subsets.len() + j]
+    }
+
+    // Pares (S_i, S_j, |S_i ∩ S_j|) con intersección no vacía, de mayor a menor solapamiento
+    pub fn overlapping_pairs(&self) -> Vec<(usize, usize, usize)> {
+        let size = self.subsets.len();
+        let mut pairs: Vec<(usize, usize, usize)> = (0..size)
+            .flat_map(|i| ((i + 1)..size).map(move |j| (i, j)))
+            .map(|(i, j)| (self.subsets[i], self.subsets[j], self.overlap(i, j)))
+            .filter(|&(_, _, shared)| shared > 0)
+            .collect();
+        pairs.sort_by(|a, b| b.2.cmp(&a.2).then((a.0, a.1).cmp(&(b.0, b.1))));
+        pairs
+    }
+
+    pub fn top_pairs(&self, n : usize) -> Vec<(usize, usize, usize)> {
+        let mut pairs = self.overlapping_pairs();
+        pairs.truncate(n);
+        pairs
+    }
+
+    // Subconjuntos de la familia disjuntos de todos los demás
+    pub fn disjoint_subsets(&self) -> Vec<usize> {
+        let size = self.subsets.len();
+        let mut disjoint: Vec<usize> = (0..size)
+            .filter(|&i| (0..size).all(|j| i == j || self.overlap(i, j) == 0))
+            .map(|i| self.subsets[i])
+            .collect();
+        disjoint.sort_unstable();
+        disjoint.dedup();
+        disjoint
+    }
+}
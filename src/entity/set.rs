@@ -1,57 +1,397 @@
-use std::collections::{HashMap, HashSet};
+use std::borrow::Cow;
+use std::collections::{BTreeMap, HashSet};
 use std::fmt;
 
-#[derive(Debug,Clone)]
+use serde_json::Value;
+
+use super::incidence::Incidence;
+use super::labels::Labels;
+use super::overlap::OverlapAnalysis;
+use crate::utils::natural_order::natural_cmp;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum SetError {
+    NoSubsets,
+    EmptySubset { subset : usize },
+    EmptyElement { subset : usize },
+    EmptyUniverseElement,
+    UnknownElement { subset : usize, element : String },
+    UndeclaredElement(String),
+    UnknownSubset(usize),
+    ElementOutOfRange { subset : usize, element : usize, size_set : usize },
+    UncoverableElements(Vec<String>),
+    InsufficientCoverage { required : usize, coverable : usize },
+    InsufficientMultiplicity { element : String, demand : usize, available : usize },
+    EmptySubsetName { subset : usize },
+    DuplicateSubsetName { name : String, first : usize, second : usize },
+}
+
+impl fmt::Display for SetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SetError::NoSubsets => write!(f, "La instancia no contiene subconjuntos"),
+            SetError::EmptySubset { subset } => write!(f, "El subconjunto {} está vacío", subset),
+            SetError::EmptyElement { subset } => write!(f, "El subconjunto {} contiene un elemento vacío", subset),
+            SetError::EmptyUniverseElement => write!(f, "El universo contiene un elemento vacío"),
+            SetError::UnknownElement { subset, element } => {
+                write!(f, "El elemento {} del subconjunto {} no pertenece al universo", element, subset)
+            }
+            SetError::UndeclaredElement(element) => write!(f, "El elemento {} no pertenece a la instancia", element),
+            SetError::UnknownSubset(subset) => write!(f, "El subconjunto {} no existe en la instancia", subset),
+            SetError::ElementOutOfRange { subset, element, size_set } => {
+                write!(f, "El elemento {} del subconjunto {} está fuera del rango [0, {})", element, subset, size_set)
+            }
+            SetError::UncoverableElements(elements) => {
+                write!(f, "Ningún subconjunto cubre los elementos del universo: {}", elements.join(", "))
+            }
+            SetError::InsufficientCoverage { required, coverable } => {
+                write!(f, "Se requieren {} elementos cubiertos pero solo {} son cubribles", required, coverable)
+            }
+            SetError::InsufficientMultiplicity { element, demand, available } => {
+                write!(f, "El elemento {} requiere {} coberturas pero solo {} subconjuntos lo contienen", element, demand, available)
+            }
+            SetError::EmptySubsetName { subset } => write!(f, "El subconjunto {} tiene un nombre vacío", subset),
+            SetError::DuplicateSubsetName { name, first, second } => {
+                write!(f, "Los subconjuntos {} y {} tienen el mismo nombre {}", first, second, name)
+            }
+        }
+    }
+}
+
+impl std::error::Error for SetError {}
+
+// Política para asignar índices a los elementos del universo
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ElementOrder {
+    // Orden de primera aparición en el archivo (#universe primero)
+    #[default]
+    FirstSeen,
+    Lexicographic,
+    // Orden natural: "2" antes que "10"
+    Natural,
+}
+
+impl ElementOrder {
+    pub fn from_name(name : &str) -> Option<Self> {
+        match name {
+            "first-seen" => Some(ElementOrder::FirstSeen),
+            "lexicographic" => Some(ElementOrder::Lexicographic),
+            "natural" => Some(ElementOrder::Natural),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for ElementOrder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ElementOrder::FirstSeen => write!(f, "first-seen"),
+            ElementOrder::Lexicographic => write!(f, "lexicographic"),
+            ElementOrder::Natural => write!(f, "natural"),
+        }
+    }
+}
+
+// Las etiquetas usan mapas ordenados para que dos cargas del mismo archivo produzcan Sets
+// idénticos, también en su salida Debug
+#[derive(Debug, Clone, PartialEq)]
 pub struct Set {
-    pub elements : HashMap<String,usize>,
-    pub matrix: Vec<bool>,
+    pub labels : Labels,
+    // Vistas dispersas de la matriz: elementos de cada subconjunto (CSR) y subconjuntos que
+    // cubren cada elemento (CSC)
+    pub rows : Incidence,
+    pub columns : Incidence,
     pub size_set : usize,
     pub size_subsets : usize,
     pub min_subset : usize,
-    pub max_subset : usize
+    pub max_subset : usize,
+    pub demands : Vec<usize>,
+    pub subset_costs : Vec<f64>,
+    pub element_weights : Vec<f64>,
+    // Nombres opcionales de los subconjuntos ("route-17"); sin nombre se muestran como S<índice>
+    pub subset_names : Vec<Option<String>>,
+    // Atributos libres de cada subconjunto ("zona", "capacidad", ...) que se conservan al convertir
+    pub subset_attributes : Vec<BTreeMap<String, Value>>,
+    // Datos libres de la instancia que se copian a los reportes
+    pub metadata : BTreeMap<String, Value>
 }
 
 impl Set {
     pub fn new(subsets : Vec<Vec<String>>) -> Self{
-        let mut elements: HashMap<String, usize> = HashMap::new();
-
-        let mut index: usize = 0;
-        for subset in subsets.clone() {
-            for element in subset {
-                if !elements.contains_key(&element){
-                    elements.insert(element, index);
-                    index += 1;
-                }
+        Set::build(&[], subsets)
+    }
+
+    // Los elementos se internan en orden de aparición, empezando por el universo declarado
+    fn build(universe : &[String], subsets : Vec<Vec<String>>) -> Self{
+        let mut labels = Labels::named(universe);
+        let indices: Vec<Vec<usize>> = subsets.iter()
+            .map(|subset| subset.iter().map(|element| labels.intern(element)).collect())
+            .collect();
+
+        Set::from_rows(labels, indices.len(), indices.iter().map(Vec::as_slice))
+    }
+
+    // Para instancias que ya vienen como índices densos 0..size_set; las etiquetas son los
+    // mismos índices
+    pub fn from_indices(size_set : usize, subsets : Vec<Vec<usize>>) -> Result<Self, SetError> {
+        Set::validate_index_subsets(&subsets)?;
+        for (index, subset) in subsets.iter().enumerate() {
+            if let Some(&element) = subset.iter().find(|&&element| element >= size_set) {
+                return Err(SetError::ElementOutOfRange { subset : index, element, size_set });
             }
         }
 
-        let size: usize = elements.len();
+        Ok(Set::from_rows(Labels::numeric(0..size_set as u32), subsets.len(), subsets.iter().map(Vec::as_slice)))
+    }
+
+    // Para instancias con etiquetas enteras arbitrarias, que se internan en orden de aparición
+    pub fn from_ids(subsets : Vec<Vec<u32>>) -> Result<Self, SetError> {
+        Set::validate_index_subsets(&subsets)?;
+
+        let labels = Labels::numeric(subsets.iter().flatten().copied());
+        let indices: Vec<Vec<usize>> = subsets.iter()
+            .map(|subset| subset.iter()
+                .filter_map(|&id| match &labels {
+                    Labels::Numeric { index_of, .. } => index_of.get(&id).copied(),
+                    Labels::Named { .. } => None,
+                })
+                .collect())
+            .collect();
+
+        Ok(Set::from_rows(labels, indices.len(), indices.iter().map(Vec::as_slice)))
+    }
+
+    fn validate_index_subsets<T>(subsets : &[Vec<T>]) -> Result<(), SetError> {
+        if subsets.is_empty() {
+            return Err(SetError::NoSubsets);
+        }
+        match subsets.iter().position(|subset| subset.is_empty()) {
+            Some(subset) => Err(SetError::EmptySubset { subset }),
+            None => Ok(()),
+        }
+    }
 
-        let lengths = subsets.iter().map(|s| s.len());
+    // Construye el Set a partir de filas de índices ya validadas contra las etiquetas
+    pub(crate) fn from_rows<'a>(labels : Labels, size_subsets : usize, rows : impl Iterator<Item = &'a [usize]>) -> Self {
+        let size: usize = labels.len();
+        let rows = Incidence::from_rows(rows);
+        debug_assert_eq!(rows.len(), size_subsets);
+        let columns = rows.transpose(size);
+        let lengths = (0..size_subsets).map(|i| rows.row_len(i));
         let min_subset = lengths.clone().min().unwrap_or(0);
         let max_subset = lengths.max().unwrap_or(0);
 
-        let mut matrix: Vec<bool> = vec![false; size * size];
+        Set {
+            labels,
+            rows,
+            columns,
+            size_set : size,
+            size_subsets,
+            min_subset,
+            max_subset,
+            demands : vec![1; size],
+            subset_costs : vec![1.0; size_subsets],
+            element_weights : vec![1.0; size],
+            subset_names : vec![None; size_subsets],
+            subset_attributes : vec![BTreeMap::new(); size_subsets],
+            metadata : BTreeMap::new()
+        }
+    }
+
+    // Reasigna los índices de los elementos según la política; las filas, las demandas y los
+    // pesos se permutan junto con ellos
+    pub fn reorder_elements(&mut self, order : ElementOrder) {
+        if order == ElementOrder::FirstSeen {
+            return;
+        }
+        let mut old_indices: Vec<usize> = (0..self.size_set).collect();
+        old_indices.sort_by(|&a, &b| self.labels.compare(a, b, order));
 
+        let mut new_index_of: Vec<usize> = vec![0; self.size_set];
+        for (new_index, &old_index) in old_indices.iter().enumerate() {
+            new_index_of[old_index] = new_index;
+        }
+        for index in self.rows.indices.iter_mut() {
+            *index = new_index_of[*index];
+        }
+        for subset in 0..self.size_subsets {
+            let (start, end) = (self.rows.offsets[subset], self.rows.offsets[subset + 1]);
+            self.rows.indices[start..end].sort_unstable();
+        }
 
-        for (i, subset) in subsets.iter().enumerate() {
-            for element in subset {
-                let index: usize = *elements.get(element)
-                    .expect("Elemento del subconjunto no encontrado en el mapa 'elements'");
+        self.columns = self.rows.transpose(self.size_set);
+        self.labels.permute(&old_indices);
+        self.demands = old_indices.iter().map(|&index| self.demands[index]).collect();
+        self.element_weights = old_indices.iter().map(|&index| self.element_weights[index]).collect();
+    }
 
-                matrix[i * size + index] = true;
+    pub fn set_subset_costs(&mut self, costs : &[(usize, f64)]) -> Result<(), SetError> {
+        for &(subset, cost) in costs {
+            if subset >= self.size_subsets {
+                return Err(SetError::UnknownSubset(subset));
             }
+            self.subset_costs[subset] = cost;
+        }
+        Ok(())
+    }
+
+    pub fn set_element_weights(&mut self, weights : &[(String, f64)]) -> Result<(), SetError> {
+        for (element, weight) in weights {
+            let index = self.labels.index_of(element)
+                .ok_or_else(|| SetError::UndeclaredElement(element.clone()))?;
+            self.element_weights[index] = *weight;
         }
+        Ok(())
+    }
 
+    pub fn set_subset_names(&mut self, names : &[(usize, String)]) -> Result<(), SetError> {
+        for (subset, name) in names {
+            if *subset >= self.size_subsets {
+                return Err(SetError::UnknownSubset(*subset));
+            }
+            if name.trim().is_empty() {
+                return Err(SetError::EmptySubsetName { subset : *subset });
+            }
+            if let Some(first) = self.get_subset_index(name).filter(|first| first != subset) {
+                return Err(SetError::DuplicateSubsetName { name : name.clone(), first, second : *subset });
+            }
+            self.subset_names[*subset] = Some(name.clone());
+        }
+        Ok(())
+    }
 
-        Set {
-            elements, 
-            matrix,
-            size_set : size,
-            size_subsets : subsets.len(),
-            min_subset,
-            max_subset
+    pub fn set_subset_attributes(&mut self, attributes : &[(usize, BTreeMap<String, Value>)]) -> Result<(), SetError> {
+        if let Some((subset, _)) = attributes.iter().find(|(subset, _)| *subset >= self.size_subsets) {
+            return Err(SetError::UnknownSubset(*subset));
+        }
+        for (subset, values) in attributes {
+            self.subset_attributes[*subset] = values.clone();
+        }
+        Ok(())
+    }
+
+    pub fn has_subset_names(&self) -> bool {
+        self.subset_names.iter().any(Option::is_some)
+    }
+
+    pub fn get_subset_name(&self, subset_index : usize) -> Cow<'_, str> {
+        match self.subset_names.get(subset_index) {
+            Some(Some(name)) => Cow::Borrowed(name.as_str()),
+            _ => Cow::Owned(format!("S{}", subset_index)),
+        }
+    }
+
+    pub fn get_subset_index(&self, name : &str) -> Option<usize> {
+        self.subset_names.iter().position(|subset_name| subset_name.as_deref() == Some(name))
+    }
+
+    pub fn set_metadata(&mut self, metadata : BTreeMap<String, Value>) {
+        self.metadata = metadata;
+    }
+
+    pub fn get_subset_cost(&self, subset_index : usize) -> f64 {
+        self.subset_costs.get(subset_index).copied().unwrap_or(1.0)
+    }
+
+    pub fn get_element_weight(&self, element_index : usize) -> f64 {
+        self.element_weights.get(element_index).copied().unwrap_or(1.0)
+    }
+
+    pub fn total_weight(&self) -> f64 {
+        self.element_weights.iter().sum()
+    }
+
+    pub fn subsets_cost(&self, subsets : &[usize]) -> f64 {
+        subsets.iter().map(|&index| self.get_subset_cost(index)).sum()
+    }
+
+    pub fn uncovered_weight(&self, subsets : &[usize]) -> Result<f64, SetError> {
+        Ok(self.total_weight() - self.covered_weight(subsets)?)
+    }
+
+    pub fn covered_weight(&self, subsets : &[usize]) -> Result<f64, SetError> {
+        let covered = self.coverage_bitmap(subsets)?;
+        Ok(covered.iter()
+            .zip(self.element_weights.iter())
+            .filter(|&(&is_covered, _)| is_covered)
+            .map(|(_, &weight)| weight)
+            .sum())
+    }
+
+    pub fn set_demands(&mut self, demands : &[(String, usize)]) -> Result<(), SetError> {
+        for (element, demand) in demands {
+            let index = self.labels.index_of(element)
+                .ok_or_else(|| SetError::UndeclaredElement(element.clone()))?;
+            self.demands[index] = *demand;
         }
+        Ok(())
+    }
+
+    pub fn get_demand(&self, element_index : usize) -> usize {
+        self.demands.get(element_index).copied().unwrap_or(1)
+    }
+
+    pub fn try_new(subsets : Vec<Vec<String>>) -> Result<Self, SetError> {
+        Set::validate_subsets(&subsets)?;
+        Ok(Set::new(subsets))
+    }
+
+    pub fn with_universe(universe : Vec<String>, subsets : Vec<Vec<String>>) -> Result<Self, SetError> {
+        let set = Set::from_universe(universe, subsets)?;
+        set.check_coverable()?;
+        Ok(set)
+    }
+
+    pub fn from_universe(universe : Vec<String>, subsets : Vec<Vec<String>>) -> Result<Self, SetError> {
+        Set::validate_subsets(&subsets)?;
+
+        let declared: HashSet<&String> = universe.iter().collect();
+        if declared.iter().any(|element| element.is_empty()) {
+            return Err(SetError::EmptyUniverseElement);
+        }
+        for (index, subset) in subsets.iter().enumerate() {
+            if let Some(element) = subset.iter().find(|element| !declared.contains(element)) {
+                return Err(SetError::UnknownElement { subset : index, element : element.clone() });
+            }
+        }
+
+        Ok(Set::build(&universe, subsets))
+    }
+
+    pub fn uncoverable_elements(&self) -> Vec<String> {
+        let mut uncoverable: Vec<String> = (0..self.size_set)
+            .filter(|&index| self.columns.row_len(index) == 0)
+            .filter_map(|index| self.get_element_name(index))
+            .map(|name| name.into_owned())
+            .collect();
+        uncoverable.sort_by(|a, b| natural_cmp(a, b));
+        uncoverable
+    }
+
+    pub fn check_coverable(&self) -> Result<(), SetError> {
+        let uncoverable = self.uncoverable_elements();
+        if uncoverable.is_empty() {
+            Ok(())
+        } else {
+            Err(SetError::UncoverableElements(uncoverable))
+        }
+    }
+
+    fn validate_subsets(subsets : &[Vec<String>]) -> Result<(), SetError> {
+        if subsets.is_empty() {
+            return Err(SetError::NoSubsets);
+        }
+
+        for (index, subset) in subsets.iter().enumerate() {
+            if subset.is_empty() {
+                return Err(SetError::EmptySubset { subset : index });
+            }
+            if subset.iter().any(|element| element.is_empty()) {
+                return Err(SetError::EmptyElement { subset : index });
+            }
+        }
+
+        Ok(())
     }
 
     pub fn get_size_set(&self) -> usize {
@@ -59,12 +399,9 @@ impl Set {
     }
 
     pub fn get_elements(&self) -> Vec<String> {
-        let mut element_list: Vec<String> = self.elements
-            .iter()
-            .map(|(name, _)| format!("{}", name))
-            .collect();
+        let mut element_list: Vec<String> = self.labels.to_strings();
 
-       element_list.sort();
+       element_list.sort_by(|a, b| natural_cmp(a, b));
        element_list
     }
 
@@ -77,124 +414,204 @@ impl Set {
         self.max_subset
     }
 
-    pub fn count_disjoint_subsets(&self, subsets : &Vec<usize>) -> usize {
+    // Cuántos subconjuntos de la familia son disjuntos de todos los demás
+    pub fn count_disjoint_subsets(&self, subsets : &[usize]) -> usize {
         if subsets.len() < 2 {
             return 0;
         }
 
-        let mut subconjuntos_con_disjuntos: HashSet<usize> = HashSet::new();
-
-        let mut subset_elements: HashMap<usize, HashSet<usize>> = HashMap::new();
-        for &index in subsets.iter() {
-            if let Ok(elements) = self.get_elements_in_subset(index) {
-                subset_elements.insert(index, elements.into_iter().collect());
-            }
+        match self.overlap_analysis(subsets) {
+            Ok(analysis) => analysis.disjoint_subsets().len(),
+            Err(_) => 0,
         }
+    }
 
-        println!("Inicia algoritmo");
-        for i in 0..subsets.len() {
-            let mut is_fully_disjoint = true;
+    pub fn overlap_analysis(&self, subsets : &[usize]) -> Result<OverlapAnalysis, SetError> {
+        OverlapAnalysis::new(subsets, self)
+    }
 
-            let index_i = subsets[i];
-            let set_i = subset_elements.get(&index_i).expect("Subconjunto I no encontrado");
+    pub fn get_element_index(&self, element : &str) -> Option<usize> {
+        self.labels.index_of(element)
+    }
 
-            for j in 0..subsets.len() {
-                if i == j {
-                    continue;
-                }
+    pub fn get_element_name(&self, element_index : usize) -> Option<Cow<'_, str>> {
+        self.labels.label(element_index)
+    }
 
-                let index_j = subsets[j];
-                let set_j = subset_elements.get(&index_j).expect("Subconjunto J no encontrado");
+    pub fn check_subset_indices(&self, subsets : &[usize]) -> Result<(), SetError> {
+        match subsets.iter().find(|&&index| index >= self.size_subsets) {
+            Some(&subset) => Err(SetError::UnknownSubset(subset)),
+            None => Ok(()),
+        }
+    }
 
-                let is_disjoint = set_i.is_disjoint(set_j);
-                if !is_disjoint {
-                    is_fully_disjoint = false;
-                    break;
-                }
-            }
+    // Mapa de bits de tamaño |X|: true en los elementos cubiertos por la familia
+    pub fn coverage_bitmap(&self, subsets : &[usize]) -> Result<Vec<bool>, SetError> {
+        self.check_subset_indices(subsets)?;
 
-            if is_fully_disjoint {
-                subconjuntos_con_disjuntos.insert(index_i);
+        let mut covered: Vec<bool> = vec![false; self.size_set];
+        for &subset_index in subsets {
+            for &element_index in self.elements_of(subset_index) {
+                covered[element_index] = true;
             }
         }
-
-        subconjuntos_con_disjuntos.len()
+        Ok(covered)
     }
 
+    // Índices de los elementos de la unión, en orden creciente
+    pub fn union_indices(&self, subsets : &[usize]) -> Result<Vec<usize>, SetError> {
+        let covered = self.coverage_bitmap(subsets)?;
+        Ok(covered.iter()
+            .enumerate()
+            .filter(|&(_, &is_covered)| is_covered)
+            .map(|(index, _)| index)
+            .collect())
+    }
 
-    pub fn union_subset(&self, subsets : &Vec<usize>) -> Result<Vec<String>,String> {
-        let all_element_under_limit = subsets.iter().all(|&element| {
-            element < self.size_subsets
-        });
+    pub fn union_size(&self, subsets : &[usize]) -> Result<usize, SetError> {
+        Ok(self.coverage_bitmap(subsets)?.iter().filter(|&&is_covered| is_covered).count())
+    }
 
-        if !all_element_under_limit {
-            let element_fail = subsets.iter()
-                            .find(|&element| *element >= self.size_subsets)
-                            .unwrap();
-            return Err(format!(
-                "Error de validación: El elemento {} está fuera del limite {}",
-                element_fail,self.size_subsets
-            ))
-        }  
+    // Nombres de los elementos de la unión, en el orden de sus índices
+    pub fn union_subset(&self, subsets : &[usize]) -> Result<Vec<String>, SetError> {
+        Ok(self.union_indices(subsets)?
+            .into_iter()
+            .filter_map(|index| self.get_element_name(index))
+            .map(|name| name.into_owned())
+            .collect())
+    }
 
-        let mut element_names: HashMap<usize, String> = HashMap::new();
-        for (name, index) in self.elements.clone() {
-            element_names.insert(index, name);
+    pub fn get_elements_in_subset(&self, subset_index: usize) -> Result<Vec<usize>, SetError> {
+        if subset_index >= self.size_subsets {
+            return Err(SetError::UnknownSubset(subset_index));
         }
 
-        let mut union_subsets : HashSet<String> = HashSet::new();
+        Ok(self.elements_of(subset_index).to_vec())
+    }
 
-        for subset_index in subsets {
-            for element_index in 0..self.size_set{
-                let matrix_index = subset_index * self.size_set + element_index;
-                
-                if self.matrix[matrix_index] {
-                    if let Some(element_name) = element_names.get(&element_index){
-                        union_subsets.insert(element_name.clone());
-                    }
-                }
+    // Elementos del subconjunto, en orden creciente
+    pub fn elements_of(&self, subset_index : usize) -> &[usize] {
+        self.rows.row(subset_index)
+    }
 
-            }
+    // Subconjuntos que cubren al elemento, en orden creciente
+    pub fn subsets_covering(&self, element_index : usize) -> &[usize] {
+        self.columns.row(element_index)
+    }
+
+    pub fn is_cover_valid(&self, subsets: &[usize]) -> bool{
+        match self.union_size(subsets) {
+            Ok(covered) => covered == self.size_set,
+            Err(_) => false,
+        } 
+    }
+
+    pub fn coverage_counts(&self, subsets : &[usize]) -> Result<Vec<usize>, SetError> {
+        let mut counts: Vec<usize> = Vec::new();
+        self.coverage_counts_into(subsets, &mut counts)?;
+        Ok(counts)
+    }
+
+    // Igual que coverage_counts pero reutilizando el buffer, para no reservar memoria en
+    // cada iteración de los solvers
+    pub fn coverage_counts_into(&self, subsets : &[usize], counts : &mut Vec<usize>) -> Result<(), SetError> {
+        self.check_subset_indices(subsets)?;
+
+        counts.clear();
+        counts.resize(self.size_set, 0);
+        for &subset_index in subsets {
+            self.add_coverage(subset_index, counts);
         }
+        Ok(())
+    }
 
-        let elements_set: Vec<String> = union_subsets.into_iter().collect();
-        Ok(elements_set)
+    // Suma a las multiplicidades los elementos de un subconjunto que se agrega a la familia
+    pub fn add_coverage(&self, subset_index : usize, counts : &mut [usize]) {
+        for &element_index in self.elements_of(subset_index) {
+            counts[element_index] += 1;
+        }
     }
 
-    pub fn get_elements_in_subset(&self, subset_index: usize) -> Result<Vec<usize>, String> {
-        if subset_index >= self.size_subsets {
-            return Err(format!("Error de validación: El subconjunto {} está fuera del límite {}", subset_index, self.size_subsets));
+    // Resta de las multiplicidades los elementos de un subconjunto que sale de la familia
+    pub fn remove_coverage(&self, subset_index : usize, counts : &mut [usize]) {
+        for &element_index in self.elements_of(subset_index) {
+            counts[element_index] -= 1;
         }
-        
-        let size = self.size_set;
-        let mut covered_element_indices: Vec<usize> = Vec::new();
+    }
 
-        for element_index in 0..size {
-            let matrix_index = subset_index * size + element_index;
-            
-            if self.matrix[matrix_index] {
-                covered_element_indices.push(element_index);
-            }
+    // Elementos cuya multiplicidad de cobertura no alcanza su demanda, con las coberturas faltantes
+    pub fn demand_shortfall(&self, subsets : &[usize]) -> Result<Vec<(String, usize)>, SetError> {
+        let counts = self.coverage_counts(subsets)?;
+        let mut shortfall: Vec<(String, usize)> = (0..self.size_set)
+            .filter(|&index| counts[index] < self.demands[index])
+            .filter_map(|index| self.get_element_name(index)
+                .map(|name| (name.into_owned(), self.demands[index] - counts[index])))
+            .collect();
+        shortfall.sort_by(|a, b| natural_cmp(&a.0, &b.0));
+        Ok(shortfall)
+    }
+
+    pub fn is_multicover_valid(&self, subsets : &[usize]) -> bool {
+        match self.demand_shortfall(subsets) {
+            Ok(shortfall) => shortfall.is_empty(),
+            Err(_) => false,
+        }
+    }
+
+    pub fn check_multicoverable(&self) -> Result<(), SetError> {
+        let available: Vec<usize> = (0..self.size_set).map(|index| self.columns.row_len(index)).collect();
+
+        let mut insufficient: Vec<(String, usize)> = (0..self.size_set)
+            .filter(|&index| available[index] < self.demands[index])
+            .filter_map(|index| self.get_element_name(index).map(|name| (name.into_owned(), index)))
+            .collect();
+        insufficient.sort_by(|a, b| natural_cmp(&a.0, &b.0));
+
+        match insufficient.into_iter().next() {
+            Some((element, index)) => Err(SetError::InsufficientMultiplicity {
+                element,
+                demand : self.demands[index],
+                available : available[index]
+            }),
+            None => Ok(()),
         }
-        
-        Ok(covered_element_indices)
     }
 
-    pub fn is_cover_valid(&self, subsets: &Vec<usize>) -> bool{
-        match self.union_subset(&subsets) {
-            Ok(union) => union.len() == self.size_set,
+    // (sobrecobertura, subcobertura) respecto a cubrir cada elemento exactamente una vez
+    pub fn partition_violation(&self, subsets : &[usize]) -> Result<(usize, usize), SetError> {
+        let counts = self.coverage_counts(subsets)?;
+        let over: usize = counts.iter().map(|&count| count.saturating_sub(1)).sum();
+        let under: usize = counts.iter().filter(|&&count| count == 0).count();
+        Ok((over, under))
+    }
+
+    pub fn is_partition_valid(&self, subsets : &[usize]) -> bool {
+        match self.partition_violation(subsets) {
+            Ok((over, under)) => over == 0 && under == 0,
             Err(_) => false,
-        } 
+        }
+    }
+
+    pub fn is_partial_cover_valid(&self, subsets: &[usize], k : usize) -> bool {
+        match self.union_size(subsets) {
+            Ok(covered) => covered >= k,
+            Err(_) => false,
+        }
     }
 }
 
 impl fmt::Display for Set {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let mut element_list: Vec<String> = self.elements
-            .iter()
+        let mut element_list: Vec<(String, usize)> = self.labels.to_strings()
+            .into_iter()
+            .enumerate()
+            .map(|(index, name)| (name, index))
+            .collect();
+        element_list.sort_by(|a, b| natural_cmp(&a.0, &b.0));
+        let element_list: Vec<String> = element_list
+            .into_iter()
             .map(|(name, index)| format!("{}: {}", name, index))
             .collect();
-        element_list.sort();
 
         write!(f, "
 Estructura del Conjunto (Set)
@@ -0,0 +1,94 @@
+use super::incidence::sort_unique_tail;
+use super::labels::Labels;
+use super::set::{Set, SetError};
+
+// Construye un Set en una sola pasada a medida que llegan los subconjuntos, guardando solo
+// índices (filas concatenadas con sus desplazamientos) en lugar de las cadenas de cada línea.
+// Las etiquetas arrancan como enteras y pasan a texto si aparece una que no lo es.
+// El primer error se guarda y se reporta en build, igual que con Set::from_universe.
+#[derive(Debug)]
+pub struct SetBuilder {
+    pub labels : Labels,
+    pub universe_size : Option<usize>,
+    pub entries : Vec<usize>,
+    pub offsets : Vec<usize>,
+    pub error : Option<SetError>
+}
+
+impl Default for SetBuilder {
+    fn default() -> Self {
+        SetBuilder::new()
+    }
+}
+
+impl SetBuilder {
+    pub fn new() -> Self {
+        SetBuilder {
+            labels : Labels::numeric(std::iter::empty()),
+            universe_size : None,
+            entries : vec![],
+            offsets : vec![0],
+            error : None
+        }
+    }
+
+    // Con universo declarado los subconjuntos solo pueden usar sus elementos
+    pub fn with_universe(universe : &[String]) -> Self {
+        let mut builder = SetBuilder::new();
+        if universe.iter().any(|element| element.is_empty()) {
+            builder.error = Some(SetError::EmptyUniverseElement);
+        }
+        for element in universe {
+            builder.labels.intern(element);
+        }
+        builder.universe_size = Some(builder.labels.len());
+        builder
+    }
+
+    pub fn size_subsets(&self) -> usize {
+        self.offsets.len() - 1
+    }
+
+    pub fn add_subset<S : AsRef<str>>(&mut self, subset : &[S]) {
+        if self.error.is_some() {
+            return;
+        }
+
+        let index = self.size_subsets();
+        if subset.is_empty() {
+            self.error = Some(SetError::EmptySubset { subset : index });
+            return;
+        }
+
+        let start = self.entries.len();
+        for element in subset.iter().map(|element| element.as_ref()) {
+            if element.is_empty() {
+                self.error = Some(SetError::EmptyElement { subset : index });
+            } else if self.universe_size.is_some() && self.labels.index_of(element).is_none() {
+                self.error = Some(SetError::UnknownElement { subset : index, element : element.to_string() });
+            }
+
+            if self.error.is_some() {
+                self.entries.truncate(start);
+                return;
+            }
+            self.entries.push(self.labels.intern(element));
+        }
+
+        // Los repetidos se quitan sobre los índices ya internados, sin comparar cadenas
+        sort_unique_tail(&mut self.entries, start);
+        self.offsets.push(self.entries.len());
+    }
+
+    pub fn build(self) -> Result<Set, SetError> {
+        if let Some(error) = self.error {
+            return Err(error);
+        }
+        if self.size_subsets() == 0 {
+            return Err(SetError::NoSubsets);
+        }
+
+        let rows = self.offsets.windows(2).map(|bounds| &self.entries[bounds[0]..bounds[1]]);
+        Ok(Set::from_rows(self.labels, self.offsets.len() - 1, rows))
+    }
+}
@@ -0,0 +1,370 @@
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+
+use serde::Serialize;
+use serde_json::Value;
+
+use super::set::Set;
+use super::subset_cover::SubsetCover;
+use crate::utils::natural_order::natural_cmp;
+
+const MAX_LISTED: usize = 10;
+const MAX_TOP_OVERLAPS: usize = 5;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum StatsFormat {
+    Table,
+    Json,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ElementFrequency {
+    pub element : String,
+    pub frequency : usize
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Component {
+    pub elements : usize,
+    pub subsets : Vec<usize>
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SetStats {
+    pub size_set : usize,
+    pub size_subsets : usize,
+    pub min_subset : usize,
+    pub max_subset : usize,
+    pub mean_subset : f64,
+    pub density : f64,
+    pub total_weight : f64,
+    pub subset_size_distribution : BTreeMap<usize, usize>,
+    pub element_frequency : Vec<ElementFrequency>,
+    pub frequency_distribution : BTreeMap<usize, usize>,
+    pub unique_elements : Vec<String>,
+    pub duplicate_subsets : Vec<Vec<usize>>,
+    pub components : Vec<Component>
+}
+
+impl SetStats {
+    pub fn new(set : &Set) -> Self {
+        let size_set = set.get_size_set();
+        let size_subsets = set.get_size_subsets();
+
+        let subsets: Vec<&[usize]> = (0..size_subsets).map(|index| set.elements_of(index)).collect();
+
+        let names = set.labels.to_strings();
+
+        let non_zero: usize = set.rows.non_zero();
+        let cells = (size_set * size_subsets) as f64;
+        let density = if cells > 0.0 { non_zero as f64 / cells } else { 0.0 };
+        let mean_subset = if size_subsets > 0 { non_zero as f64 / size_subsets as f64 } else { 0.0 };
+
+        let mut subset_size_distribution: BTreeMap<usize, usize> = BTreeMap::new();
+        for subset in subsets.iter() {
+            *subset_size_distribution.entry(subset.len()).or_insert(0) += 1;
+        }
+
+        let frequency: Vec<usize> = (0..size_set).map(|element| set.subsets_covering(element).len()).collect();
+
+        let mut element_frequency: Vec<ElementFrequency> = frequency.iter()
+            .enumerate()
+            .map(|(index, &frequency)| ElementFrequency { element: names[index].clone(), frequency })
+            .collect();
+        element_frequency.sort_by(|a, b| natural_cmp(&a.element, &b.element));
+
+        let mut frequency_distribution: BTreeMap<usize, usize> = BTreeMap::new();
+        for &f in frequency.iter() {
+            *frequency_distribution.entry(f).or_insert(0) += 1;
+        }
+
+        let unique_elements: Vec<String> = element_frequency.iter()
+            .filter(|e| e.frequency == 1)
+            .map(|e| e.element.clone())
+            .collect();
+
+        SetStats {
+            size_set,
+            size_subsets,
+            min_subset : set.min_subset,
+            max_subset : set.get_max_subset(),
+            mean_subset,
+            density,
+            total_weight : set.total_weight(),
+            subset_size_distribution,
+            element_frequency,
+            frequency_distribution,
+            unique_elements,
+            duplicate_subsets : SetStats::find_duplicates(&subsets),
+            components : SetStats::find_components(&subsets, size_set)
+        }
+    }
+
+    fn find_duplicates(subsets : &[&[usize]]) -> Vec<Vec<usize>> {
+        let mut groups: HashMap<&[usize], Vec<usize>> = HashMap::new();
+        for (index, subset) in subsets.iter().enumerate() {
+            groups.entry(*subset).or_default().push(index);
+        }
+
+        let mut duplicates: Vec<Vec<usize>> = groups.into_values()
+            .filter(|group| group.len() > 1)
+            .collect();
+        duplicates.sort();
+        duplicates
+    }
+
+    // Componentes conexas del grafo bipartito elemento-subconjunto (union-find sobre elementos)
+    fn find_components(subsets : &[&[usize]], size_set : usize) -> Vec<Component> {
+        fn find(parent : &mut [usize], x : usize) -> usize {
+            let mut root = x;
+            while parent[root] != root {
+                root = parent[root];
+            }
+            let mut current = x;
+            while parent[current] != root {
+                let next = parent[current];
+                parent[current] = root;
+                current = next;
+            }
+            root
+        }
+
+        let mut parent: Vec<usize> = (0..size_set).collect();
+        for subset in subsets.iter() {
+            if let Some((&first, rest)) = subset.split_first() {
+                for &element in rest {
+                    let a = find(&mut parent, first);
+                    let b = find(&mut parent, element);
+                    if a != b {
+                        parent[b] = a;
+                    }
+                }
+            }
+        }
+
+        let mut by_root: BTreeMap<usize, Component> = BTreeMap::new();
+        for element in 0..size_set {
+            let root = find(&mut parent, element);
+            by_root.entry(root).or_insert(Component { elements: 0, subsets: vec![] }).elements += 1;
+        }
+        for (index, subset) in subsets.iter().enumerate() {
+            match subset.first() {
+                Some(&first) => {
+                    let root = find(&mut parent, first);
+                    if let Some(component) = by_root.get_mut(&root) {
+                        component.subsets.push(index);
+                    }
+                }
+                None => {
+                    by_root.insert(size_set + index, Component { elements: 0, subsets: vec![index] });
+                }
+            }
+        }
+
+        let mut components: Vec<Component> = by_root.into_values().collect();
+        components.sort_by(|a, b| b.elements.cmp(&a.elements).then(a.subsets.cmp(&b.subsets)));
+        components
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap_or_else(|e| format!("{{\"error\": \"{}\"}}", e))
+    }
+
+    pub fn render(&self, format : StatsFormat) -> String {
+        match format {
+            StatsFormat::Table => self.to_string(),
+            StatsFormat::Json => self.to_json(),
+        }
+    }
+}
+
+fn truncated_list<T : fmt::Display>(items : &[T]) -> String {
+    let mut list: Vec<String> = items.iter().take(MAX_LISTED).map(|i| i.to_string()).collect();
+    if items.len() > MAX_LISTED {
+        list.push(format!("... (+{})", items.len() - MAX_LISTED));
+    }
+    list.join(", ")
+}
+
+impl fmt::Display for SetStats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let min_frequency = self.frequency_distribution.keys().next().copied().unwrap_or(0);
+        let max_frequency = self.frequency_distribution.keys().last().copied().unwrap_or(0);
+        let mean_frequency = if self.size_set > 0 {
+            self.element_frequency.iter().map(|e| e.frequency).sum::<usize>() as f64 / self.size_set as f64
+        } else {
+            0.0
+        };
+
+        writeln!(f, "Estadísticas de la instancia")?;
+        writeln!(f, "----------------------------------")?;
+        writeln!(f, "| {:<38} | {:>12} |", "Elementos (|X|)", self.size_set)?;
+        writeln!(f, "| {:<38} | {:>12} |", "Subconjuntos (|S|)", self.size_subsets)?;
+        writeln!(f, "| {:<38} | {:>12.6} |", "Densidad", self.density)?;
+        writeln!(f, "| {:<38} | {:>12.3} |", "Peso total de los elementos", self.total_weight)?;
+        writeln!(f, "| {:<38} | {:>5} / {:>4} |", "Tamaño Min/Max de subconjunto", self.min_subset, self.max_subset)?;
+        writeln!(f, "| {:<38} | {:>12.3} |", "Tamaño promedio de subconjunto", self.mean_subset)?;
+        writeln!(f, "| {:<38} | {:>5} / {:>4} |", "Frecuencia Min/Max de elemento", min_frequency, max_frequency)?;
+        writeln!(f, "| {:<38} | {:>12.3} |", "Frecuencia promedio de elemento", mean_frequency)?;
+        writeln!(f, "| {:<38} | {:>12} |", "Elementos cubiertos una sola vez", self.unique_elements.len())?;
+        writeln!(f, "| {:<38} | {:>12} |", "Grupos de subconjuntos duplicados", self.duplicate_subsets.len())?;
+        writeln!(f, "| {:<38} | {:>12} |", "Componentes conexas", self.components.len())?;
+        writeln!(f, "----------------------------------")?;
+
+        writeln!(f, "Distribución de tamaños (tamaño: subconjuntos)")?;
+        for (size, count) in self.subset_size_distribution.iter() {
+            writeln!(f, "|   {:>6}: {}", size, count)?;
+        }
+        writeln!(f, "Distribución de frecuencias (frecuencia: elementos)")?;
+        for (frequency, count) in self.frequency_distribution.iter() {
+            writeln!(f, "|   {:>6}: {}", frequency, count)?;
+        }
+        writeln!(f, "----------------------------------")?;
+
+        if !self.unique_elements.is_empty() {
+            writeln!(f, "Elementos cubiertos una sola vez: {}", truncated_list(&self.unique_elements))?;
+        }
+        if !self.duplicate_subsets.is_empty() {
+            let groups: Vec<String> = self.duplicate_subsets.iter()
+                .map(|group| format!("{:?}", group))
+                .collect();
+            writeln!(f, "Subconjuntos duplicados: {}", truncated_list(&groups))?;
+        }
+        let sizes: Vec<String> = self.components.iter()
+            .map(|c| format!("{}x{}", c.elements, c.subsets.len()))
+            .collect();
+        writeln!(f, "Componentes (elementos x subconjuntos): {}", truncated_list(&sizes))
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CoverReport {
+    pub objective : Option<String>,
+    pub objective_cost : Option<f64>,
+    pub subsets : Vec<usize>,
+    // Nombres de los subconjuntos seleccionados, en el mismo orden, si la instancia los tiene
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub subset_names : Vec<String>,
+    pub size : usize,
+    pub cost : f64,
+    pub covered : usize,
+    pub uncovered : usize,
+    pub covered_weight : f64,
+    pub uncovered_weight : f64,
+    pub uncovered_elements : Vec<String>,
+    pub redundancy : usize,
+    // (S_i, S_j, |S_i ∩ S_j|) de los pares más solapados
+    pub top_overlaps : Vec<(usize, usize, usize)>,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub metadata : BTreeMap<String, Value>
+}
+
+impl CoverReport {
+    pub fn new(subsets : &[usize], set : &Set) -> Self {
+        let covered = set.coverage_bitmap(subsets).unwrap_or_else(|_| vec![false; set.get_size_set()]);
+        let covered_count = covered.iter().filter(|&&is_covered| is_covered).count();
+        let covered_weight = set.covered_weight(subsets).unwrap_or(0.0);
+
+        let mut uncovered_elements: Vec<String> = covered.iter()
+            .enumerate()
+            .filter(|&(_, &is_covered)| !is_covered)
+            .filter_map(|(index, _)| set.get_element_name(index))
+            .map(|name| name.to_string())
+            .collect();
+        uncovered_elements.sort_by(|a, b| natural_cmp(a, b));
+
+        let overlap = set.overlap_analysis(subsets).ok();
+        let subset_names: Vec<String> = if set.has_subset_names() {
+            subsets.iter().map(|&subset| set.get_subset_name(subset).into_owned()).collect()
+        } else {
+            Vec::new()
+        };
+
+        CoverReport {
+            objective : None,
+            objective_cost : None,
+            subsets : subsets.to_vec(),
+            subset_names,
+            size : subsets.len(),
+            cost : set.subsets_cost(subsets),
+            covered : covered_count,
+            uncovered : set.get_size_set() - covered_count,
+            covered_weight,
+            uncovered_weight : set.total_weight() - covered_weight,
+            uncovered_elements,
+            redundancy : overlap.as_ref().map(|analysis| analysis.redundancy).unwrap_or(0),
+            top_overlaps : overlap.map(|analysis| analysis.top_pairs(MAX_TOP_OVERLAPS)).unwrap_or_default(),
+            metadata : set.metadata.clone()
+        }
+    }
+
+    pub fn from_cover(cover : &SubsetCover, set : &Set) -> Self {
+        let mut report = CoverReport::new(&cover.elements, set);
+        report.objective = Some(cover.get_objective_name().to_string());
+        report.objective_cost = Some(cover.cost);
+        report
+    }
+
+    // Nombre de un subconjunto seleccionado, o S<índice> si la instancia no tiene nombres
+    fn subset_name(&self, subset : usize) -> String {
+        self.subsets.iter()
+            .position(|&selected| selected == subset)
+            .and_then(|position| self.subset_names.get(position))
+            .cloned()
+            .unwrap_or_else(|| format!("S{}", subset))
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap_or_else(|e| format!("{{\"error\": \"{}\"}}", e))
+    }
+
+    pub fn render(&self, format : StatsFormat) -> String {
+        match format {
+            StatsFormat::Table => self.to_string(),
+            StatsFormat::Json => self.to_json(),
+        }
+    }
+}
+
+impl fmt::Display for CoverReport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "Resumen de la cobertura")?;
+        writeln!(f, "----------------------------------")?;
+        if let (Some(objective), Some(cost)) = (&self.objective, self.objective_cost) {
+            writeln!(f, "| {:<38} | {:>12} |", "Función objetivo", objective)?;
+            writeln!(f, "| {:<38} | {:>12.6} |", "Valor de la función objetivo", cost)?;
+        }
+        writeln!(f, "| {:<38} | {:>12} |", "Subconjuntos seleccionados (|C|)", self.size)?;
+        writeln!(f, "| {:<38} | {:>12.3} |", "Costo de los subconjuntos", self.cost)?;
+        writeln!(f, "| {:<38} | {:>12} |", "Elementos cubiertos", self.covered)?;
+        writeln!(f, "| {:<38} | {:>12} |", "Elementos sin cubrir", self.uncovered)?;
+        writeln!(f, "| {:<38} | {:>12.3} |", "Peso cubierto", self.covered_weight)?;
+        writeln!(f, "| {:<38} | {:>12.3} |", "Peso sin cubrir", self.uncovered_weight)?;
+        writeln!(f, "| {:<38} | {:>12} |", "Redundancia (coberturas extra)", self.redundancy)?;
+        writeln!(f, "----------------------------------")?;
+        if self.subset_names.is_empty() {
+            writeln!(f, "Subconjuntos: {}", truncated_list(&self.subsets))?;
+        } else {
+            writeln!(f, "Subconjuntos: {}", truncated_list(&self.subset_names))?;
+        }
+        if !self.top_overlaps.is_empty() {
+            let pairs: Vec<String> = self.top_overlaps.iter()
+                .map(|&(i, j, shared)| format!("{}∩{}={}", self.subset_name(i), self.subset_name(j), shared))
+                .collect();
+            writeln!(f, "Pares más solapados: {}", pairs.join(", "))?;
+        }
+        if !self.uncovered_elements.is_empty() {
+            writeln!(f, "Elementos sin cubrir: {}", truncated_list(&self.uncovered_elements))?;
+        }
+        if !self.metadata.is_empty() {
+            writeln!(f, "Metadatos de la instancia")?;
+            for (key, value) in self.metadata.iter() {
+                match value {
+                    Value::String(text) => writeln!(f, "|   {}: {}", key, text)?,
+                    other => writeln!(f, "|   {}: {}", key, other)?,
+                }
+            }
+        }
+        Ok(())
+    }
+}
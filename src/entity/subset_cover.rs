@@ -1,45 +1,87 @@
-use super::set::Set;
+use std::sync::Arc;
 
+use super::cover_mode::CoverMode;
+use super::objective::{DefaultObjective, Objective};
+use super::set::{Set, SetError};
+
+#[derive(Clone)]
 pub struct SubsetCover {
     pub elements : Vec<usize>,
     pub cost : f64,
-    pub size : usize
+    pub size : usize,
+    pub mode : CoverMode,
+    pub objective : Arc<dyn Objective>
 }
 
 impl SubsetCover {
     pub fn new(set : Set) -> Self{
+        SubsetCover::with_mode(&set, CoverMode::Full)
+    }
+
+    pub fn with_mode(set : &Set, mode : CoverMode) -> Self{
+        SubsetCover::with_objective(set, mode, Arc::new(DefaultObjective))
+    }
+
+    pub fn with_objective(set : &Set, mode : CoverMode, objective : Arc<dyn Objective>) -> Self{
         SubsetCover { 
             elements: vec![], 
-            cost: SubsetCover::calculate_cost(&vec![],&set), 
-            size : 0
+            cost: objective.cost(&[], set, &mode), 
+            size : 0,
+            mode,
+            objective
         }
     }
 
-    pub fn add_subset(&mut self, subset_index: usize, set: &Set) -> f64 {
+    pub fn get_objective_name(&self) -> &str {
+        self.objective.name()
+    }
+
+    pub fn is_feasible(&self, set : &Set) -> bool {
+        self.mode.is_feasible(&self.elements, set)
+    }
+
+    pub fn add_subset(&mut self, subset_index: usize, set: &Set) -> Result<f64, SetError> {
+        set.check_subset_indices(&[subset_index])?;
         if !self.elements.contains(&subset_index) {
             self.elements.push(subset_index);
             self.elements.sort_unstable();
             self.size = self.elements.len();
-            self.cost = SubsetCover::calculate_cost(&self.elements, set); 
+            self.cost = self.objective.cost(&self.elements, set, &self.mode); 
         }
 
+        Ok(self.cost)
+    }
+
+    pub fn set_subsets(&mut self, subsets : Vec<usize>, set : &Set) -> Result<f64, SetError> {
+        set.check_subset_indices(&subsets)?;
+        Ok(self.set_subsets_unchecked(subsets, set))
+    }
+
+    // Para familias que los solvers arman con índices de la propia instancia, sin volver a validarlos
+    pub(crate) fn set_subsets_unchecked(&mut self, mut subsets : Vec<usize>, set : &Set) -> f64 {
+        subsets.sort_unstable();
+        subsets.dedup();
+        self.size = subsets.len();
+        self.cost = self.objective.cost(&subsets, set, &self.mode);
+        self.elements = subsets;
         self.cost
     }
 
-    pub fn calculate_cost(subsets : &Vec<usize>, set : &Set) -> f64{
-        let union_subsets = match set.union_subset(subsets) {
-            Ok(e) => e,
-            Err(e) => panic!("{}",e)
-        };
+    pub fn calculate_cost(subsets : &[usize], set : &Set) -> f64{
+        SubsetCover::calculate_cost_mode(subsets, set, &CoverMode::Full)
+    }
+
+    pub fn calculate_cost_mode(subsets : &[usize], set : &Set, mode : &CoverMode) -> f64{
+        // Con subconjuntos fuera de la instancia el faltante, y con él el costo, es infinito
+        let shortfall = mode.shortfall(subsets, set).unwrap_or(f64::INFINITY);
 
-        let size_union_subsets = union_subsets.len() as f64;
         let size_subsets = subsets.len() as f64;
-        let size_elements = set.get_size_set() as f64;
         let max_subset = set.get_max_subset() as f64;
 
-        //|C| + (|X| - |Union(C)|)max(S)|S| / |S|
+        //|C| + faltante(C)max(S)|S| / |S|, con faltante = peso de X - Union(C) en cobertura completa
+        //(|X| - |Union(C)| con pesos unitarios) y max(0, k - |Union(C)|) en cobertura parcial
         let normalize: f64 = set.get_size_subsets() as f64;
-        let raw_cost: f64 = size_subsets + (size_elements - size_union_subsets) * max_subset * normalize;
+        let raw_cost: f64 = size_subsets + shortfall * max_subset * normalize;
         raw_cost / normalize
     }
 }
\ No newline at end of file
@@ -1,2 +1,3 @@
 pub mod utils;
-pub mod entity;
\ No newline at end of file
+pub mod entity;
+pub mod solver;
@@ -1,19 +1,25 @@
-mod utils;
-mod entity;
-
 use std::{env, process::exit};
-use chrono::Local;
+use std::time::Duration;
+
+use rand::SeedableRng;
+use rand::rngs::StdRng;
 
-use utils::read_input::ReadInput;
-use entity::set::Set;
-use entity::subset_cover::SubsetCover;
+use set_covering_problem::utils::read_input::ReadInput;
+use set_covering_problem::utils::instance_format::write_instance_file;
+use set_covering_problem::entity::objective::objective_from_name;
+use set_covering_problem::entity::stats::{CoverReport, SetStats};
+use set_covering_problem::solver::registry::SolverRegistry;
+use set_covering_problem::solver::SolverConfig;
+use set_covering_problem::solver::local_search::LocalSearch;
+use set_covering_problem::solver::checkpoint::{Checkpoint, CheckpointWriter};
+use set_covering_problem::solver::observer::{ConsoleObserver, ObserverGroup, TraceObserver};
 
 pub fn main(){
     let args : Vec<String> = env::args().collect();
     let mut read_input = match ReadInput::new(args) {
         Ok(read) => read,
         Err(e) => {
-            panic!("Error al leer argumentos: {:?}\nUtilice --help o -h", e);
+            panic!("Error al leer argumentos: {}\nUtilice --help o -h", e);
         }
     };
 
@@ -26,21 +32,233 @@ pub fn main(){
     let verbose_mode = read_input.get_verbose();
     let svg_mode = read_input.get_svg();
 
+    let stats_format = match read_input.get_stats() {
+        Ok(format) => format,
+        Err(e) => {
+            panic!("Error al leer el formato de estadísticas: {}", e);
+        }
+    };
+
+    let mode_request = match read_input.get_mode_request() {
+        Ok(request) => request,
+        Err(e) => {
+            panic!("Error al leer el modo de cobertura: {}", e);
+        }
+    };
+
+    let element_order = match read_input.get_element_order() {
+        Ok(order) => order,
+        Err(e) => {
+            panic!("Error al leer el orden de elementos: {}", e);
+        }
+    };
+
+    let objective = match read_input.get_objective() {
+        Ok(objective) => objective,
+        Err(e) => {
+            panic!("Error al leer la función objetivo: {}", e);
+        }
+    };
+
+    let registry = SolverRegistry::default();
+    let mut solver = match read_input.get_solver(&registry) {
+        Ok(solver) => solver,
+        Err(e) => {
+            panic!("Error al leer el solver: {}", e);
+        }
+    };
+
+    let termination = match read_input.get_termination() {
+        Ok(termination) => termination,
+        Err(e) => {
+            panic!("Error al leer los criterios de paro: {}", e);
+        }
+    };
+
+    let checkpoint = match read_input.get_checkpoint() {
+        Ok(checkpoint) => checkpoint,
+        Err(e) => {
+            panic!("Error al leer el punto de control: {}", e);
+        }
+    };
+
+    let resume = match read_input.get_resume() {
+        Ok(resume) => resume,
+        Err(e) => {
+            panic!("Error al leer el punto de control a reanudar: {}", e);
+        }
+    };
+
+    let convert = match read_input.get_convert() {
+        Ok(convert) => convert,
+        Err(e) => {
+            panic!("Error al leer la conversión: {}", e);
+        }
+    };
+
+    // Al reanudar, el generador sale del punto de control y se hace una sola corrida; al
+    // convertir no se resuelve la instancia
     let seeds = match read_input.get_seed() {
+        _ if resume.is_some() || convert.is_some() => vec![0],
         Ok(seeds) => seeds,
         Err(e) => {
-            panic!("Error al leer la semilla: {:?}",e);
+            panic!("Error al leer la semilla: {}",e);
         }
     };
 
     println!("Cargando subconjuntos del archivo");
-    let subset_vec = match read_input.read_file_subset(){
+    let set_builder = match read_input.load_set(){
         Ok(result) => result,
         Err(e) => {
-            panic!("{:?}",e);
+            panic!("Error al leer la instancia: {}", e);
+        }
+    };
+
+    for warning in read_input.get_warnings() {
+        println!("Advertencia: {}", warning);
+    }
+
+    let set_result = set_builder.build().and_then(|mut set| {
+        set.reorder_elements(element_order);
+        set.set_demands(read_input.get_demands())?;
+        set.set_subset_costs(read_input.get_subset_costs())?;
+        set.set_element_weights(read_input.get_element_weights())?;
+        set.set_subset_names(read_input.get_subset_names())?;
+        set.set_subset_attributes(read_input.get_subset_attributes())?;
+        set.set_metadata(read_input.get_metadata().clone());
+        Ok(set)
+    });
+
+    let set = match set_result {
+        Ok(set) => set,
+        Err(e) => panic!("{}", read_input.set_error(e)),
+    };
+
+    // La conversión no depende del modo: se escribe la instancia y se termina
+    if let Some((path, format)) = convert {
+        match write_instance_file(&set, format, &path) {
+            Ok(warnings) => {
+                for warning in warnings {
+                    println!("Advertencia: {}", warning);
+                }
+                println!("Instancia escrita en {} (formato {})", path, format);
+                exit(0);
+            }
+            Err(e) => panic!("Error al convertir la instancia: {}", e),
+        }
+    }
+
+    let mode = match mode_request.resolve(&set).and_then(|mode| mode.check_feasible(&set).map(|_| mode)) {
+        Ok(mode) => mode,
+        Err(e) => panic!("{}", read_input.set_error(e)),
+    };
+    println!("Modo de cobertura: {}", mode);
+    println!("Función objetivo: {}", objective.name());
+    if verbose_mode {
+        println!("{}",set);
+    }
+
+    if let Some(format) = stats_format {
+        println!("{}", SetStats::new(&set).render(format));
+    }
+
+    let polish = match read_input.get_polish() {
+        Ok(polish) => polish,
+        Err(e) => {
+            panic!("Error al leer la búsqueda local: {}", e);
         }
     };
 
-    let set = Set::new(subset_vec);
-    println!("{}",set);
-}
\ No newline at end of file
+    let initial = match read_input.get_initial_solution() {
+        Ok(initial) => initial,
+        Err(e) => {
+            panic!("Error al leer la solución inicial: {}", e);
+        }
+    };
+    // La solución inicial se valida y repara una sola vez y se reutiliza en todas las semillas
+    let initial = initial.map(|subsets| {
+        let config = SolverConfig::new(mode.clone(), objective.clone());
+        match config.warm_start(&subsets, &set) {
+            Ok((cover, repaired)) => {
+                let note = if repaired { " (reparada, no era factible)" } else { "" };
+                println!("Solución inicial: {} subconjuntos, costo {:.6}{}", cover.size, cover.cost, note);
+                cover
+            }
+            Err(e) => panic!("Solución inicial inválida: {}", e),
+        }
+    });
+
+    println!("Solver: {}", solver.name());
+    let several_seeds = seeds.len() > 1;
+    for seed in seeds {
+        // Cada semilla parte de una función objetivo nueva para que las corridas sean independientes
+        let objective = objective_from_name(objective.name()).unwrap_or_else(|| objective.clone());
+        let mut config = SolverConfig { termination, ..SolverConfig::new(mode.clone(), objective) };
+        if let Some(cover) = &initial {
+            config = config.with_initial_cover(cover, &set);
+        }
+        let mut rng = StdRng::seed_from_u64(seed as u64);
+
+        if let Some(path) = &resume {
+            let loaded = Checkpoint::load(path)
+                .and_then(|saved| saved.validate(solver.name(), &set, &config).map(|_| saved))
+                .and_then(|saved| {
+                    let iteration = saved.iteration;
+                    solver.resume_from(saved).map(|_| iteration)
+                });
+            match loaded {
+                Ok(iteration) => println!("Reanudando desde {} (iteración {})", path, iteration),
+                Err(e) => panic!("No se pudo reanudar: {}", e),
+            }
+        }
+
+        let mut console = ConsoleObserver::new(verbose_mode);
+        let mut trace = TraceObserver::new();
+        let mut writer = checkpoint.as_ref().map(|(path, interval)| {
+            config.checkpoint_interval = *interval;
+            let path = if several_seeds { format!("{}.{}", path, seed) } else { path.clone() };
+            CheckpointWriter::new(path)
+        });
+        let mut observers = ObserverGroup::new();
+        observers.push(&mut console);
+        if svg_mode {
+            observers.push(&mut trace);
+        }
+        if let Some(writer) = writer.as_mut() {
+            observers.push(writer);
+        }
+
+        if resume.is_none() {
+            println!("Semilla {}", seed);
+        }
+        let mut result = solver.solve(&set, &config, &mut rng, &mut observers);
+        drop(observers);
+        if let Some(strategy) = polish {
+            // El pulido respeta los mismos criterios de paro, con el tiempo que le dejó el solver
+            let mut polish_termination = termination;
+            polish_termination.time_limit = termination.time_limit
+                .map(|limit| limit.saturating_sub(Duration::from_secs_f64(result.stats.elapsed_secs)));
+            let (cover, search) = LocalSearch::new(strategy).polish(&result.cover, &set, &polish_termination, &mut console);
+            println!("Búsqueda local ({}): {}", strategy, search);
+            result.stats.feasible = cover.is_feasible(&set);
+            result.cover = cover;
+        }
+        if svg_mode {
+            let path = format!("convergencia_{}_{}.svg", solver.name(), seed);
+            match trace.save_svg(&path) {
+                Ok(()) => println!("Gráfica de convergencia guardada en {}", path),
+                Err(e) => println!("Advertencia: no se pudo guardar {}: {}", path, e),
+            }
+        }
+        if let Some(writer) = writer {
+            match writer.last_error {
+                Some(e) => println!("Advertencia: {}", e),
+                None if writer.saved > 0 => println!("Punto de control guardado en {}", writer.path.display()),
+                None => {}
+            }
+        }
+
+        println!("{}", CoverReport::from_cover(&result.cover, &set));
+        println!("{}", result.stats);
+    }
+}
@@ -0,0 +1,123 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use rand::SeedableRng;
+use rand_chacha::ChaCha12Rng;
+use serde::{Deserialize, Serialize};
+
+use super::{SolverConfig, SolverObserver};
+use crate::entity::set::Set;
+use crate::entity::subset_cover::SubsetCover;
+
+// Posición exacta de un generador ChaCha: semilla, flujo y palabras consumidas
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RngState {
+    pub seed : [u8; 32],
+    pub stream : u64,
+    pub word_pos : u128
+}
+
+impl RngState {
+    pub fn capture(rng : &ChaCha12Rng) -> Self {
+        RngState { seed : rng.get_seed(), stream : rng.get_stream(), word_pos : rng.get_word_pos() }
+    }
+
+    pub fn restore(&self) -> ChaCha12Rng {
+        let mut rng = ChaCha12Rng::from_seed(self.seed);
+        rng.set_stream(self.stream);
+        rng.set_word_pos(self.word_pos);
+        rng
+    }
+}
+
+// Estado completo de una corrida al terminar una iteración. Al reanudar desde él la búsqueda
+// sigue exactamente la misma trayectoria que sin interrupción.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Checkpoint {
+    pub solver : String,
+    pub mode : String,
+    pub objective : String,
+    pub size_set : usize,
+    pub size_subsets : usize,
+    pub iteration : usize,
+    pub altitudes : Vec<f64>,
+    pub best : Vec<usize>,
+    // Costo con el que se comparan las gotas y costo que reporta la cobertura; difieren cuando
+    // la función objetivo cambia durante la búsqueda
+    pub best_cost : f64,
+    pub best_cover_cost : f64,
+    pub best_iteration : usize,
+    pub evaluations : usize,
+    pub restarts : usize,
+    pub stagnation : usize,
+    pub since_restart : usize,
+    pub elapsed_secs : f64,
+    pub objective_state : Vec<f64>,
+    pub rng : RngState
+}
+
+impl Checkpoint {
+    pub fn save<P : AsRef<Path>>(&self, path : P) -> Result<(), String> {
+        let path = path.as_ref();
+        let json = serde_json::to_string(self).map_err(|e| e.to_string())?;
+
+        // Se escribe a un archivo temporal y se renombra para no dejar un punto de control a medias
+        let mut temporary = path.as_os_str().to_owned();
+        temporary.push(".tmp");
+        fs::write(&temporary, json)
+            .and_then(|_| fs::rename(&temporary, path))
+            .map_err(|e| format!("No se pudo guardar el punto de control {}: {}", path.display(), e))
+    }
+
+    pub fn load<P : AsRef<Path>>(path : P) -> Result<Self, String> {
+        let path = path.as_ref();
+        let json = fs::read_to_string(path)
+            .map_err(|e| format!("No se pudo leer el punto de control {}: {}", path.display(), e))?;
+        serde_json::from_str(&json)
+            .map_err(|e| format!("Punto de control inválido {}: {}", path.display(), e))
+    }
+
+    // Comprueba que el punto de control corresponde a la misma instancia y configuración
+    pub fn validate(&self, solver : &str, set : &Set, config : &SolverConfig) -> Result<(), String> {
+        let expected = [
+            ("solver", self.solver.clone(), solver.to_string()),
+            ("modo", self.mode.clone(), config.mode.to_string()),
+            ("función objetivo", self.objective.clone(), config.objective.name().to_string()),
+            ("elementos", self.size_set.to_string(), set.get_size_set().to_string()),
+            ("subconjuntos", self.size_subsets.to_string(), set.get_size_subsets().to_string()),
+        ];
+        for (field, found, current) in expected {
+            if found != current {
+                return Err(format!("El punto de control no coincide en {}: {} en lugar de {}", field, found, current));
+            }
+        }
+
+        if self.altitudes.len() != self.size_subsets {
+            return Err(format!("El punto de control tiene {} alturas para {} subconjuntos", self.altitudes.len(), self.size_subsets));
+        }
+        set.check_subset_indices(&self.best).map_err(|e| e.to_string())
+    }
+}
+
+// Guarda en disco cada punto de control que emite el solver, reemplazando el anterior
+#[derive(Debug, Clone)]
+pub struct CheckpointWriter {
+    pub path : PathBuf,
+    pub saved : usize,
+    pub last_error : Option<String>
+}
+
+impl CheckpointWriter {
+    pub fn new<P : Into<PathBuf>>(path : P) -> Self {
+        CheckpointWriter { path : path.into(), saved : 0, last_error : None }
+    }
+}
+
+impl SolverObserver for CheckpointWriter {
+    fn on_checkpoint(&mut self, checkpoint : &Checkpoint, _best : &SubsetCover) {
+        match checkpoint.save(&self.path) {
+            Ok(()) => self.saved += 1,
+            Err(e) => self.last_error = Some(e),
+        }
+    }
+}
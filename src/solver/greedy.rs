@@ -0,0 +1,115 @@
+use std::time::Instant;
+
+use rand::{Rng, RngCore};
+
+use super::termination::{SearchProgress, StopReason};
+use super::{prune, Solver, SolverConfig, SolverObserver, SolverResult, SolverStats};
+use crate::entity::cover_mode::CoverMode;
+use crate::entity::max_coverage::MaxCoverage;
+use crate::entity::set::Set;
+
+const TIE_TOLERANCE: f64 = 1e-12;
+
+// Greedy clásico: agrega el subconjunto con mayor reducción del faltante por unidad de costo
+// hasta que la familia es factible, repara si hace falta y quita los redundantes. Los empates
+// se rompen con el generador, así que distintas semillas pueden dar coberturas distintas.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct GreedySolver;
+
+impl GreedySolver {
+    pub fn construct(set : &Set, config : &SolverConfig, rng : &mut dyn RngCore) -> Vec<usize> {
+        // Con solución inicial la cobertura máxima se extiende desde ella mientras quepa
+        if let CoverMode::MaxCoverage(budget) = config.mode {
+            let max_coverage = MaxCoverage::new(budget);
+            return match &config.initial {
+                Some(initial) => max_coverage.repair(&initial.elements, set),
+                None => max_coverage.greedy(set),
+            };
+        }
+
+        let mut selected: Vec<usize> = config.initial.as_ref().map(|cover| cover.elements.clone()).unwrap_or_default();
+        // Las multiplicidades se actualizan al agregar cada subconjunto y los puntajes se
+        // escriben sobre el mismo buffer en cada iteración
+        let mut counts: Vec<usize> = Vec::new();
+        let mut scores: Vec<f64> = Vec::new();
+        if set.coverage_counts_into(&selected, &mut counts).is_ok() {
+            while !config.mode.is_feasible_with_counts(&selected, &counts, set) {
+                config.mode.marginal_gains_into(&selected, &counts, set, &mut scores);
+                for (index, score) in scores.iter_mut().enumerate() {
+                    *score = if *score > 0.0 { *score / set.get_subset_cost(index).max(f64::EPSILON) } else { 0.0 };
+                }
+
+                match GreedySolver::pick_best(&scores, rng) {
+                    Some(index) => {
+                        selected.push(index);
+                        set.add_coverage(index, &mut counts);
+                    }
+                    None => break,
+                }
+            }
+        }
+
+        if !config.mode.is_feasible(&selected, set) {
+            selected = config.mode.repair(&selected, set);
+        }
+        prune(&selected, set, config)
+    }
+
+    // Índice de mayor puntaje positivo, eligiendo al azar entre los empatados
+    pub fn pick_best(scores : &[f64], rng : &mut dyn RngCore) -> Option<usize> {
+        let best = scores.iter().copied().fold(0.0, f64::max);
+        if best <= 0.0 {
+            return None;
+        }
+
+        let ties: Vec<usize> = scores.iter()
+            .enumerate()
+            .filter(|&(_, &score)| best - score <= TIE_TOLERANCE)
+            .map(|(index, _)| index)
+            .collect();
+        Some(ties[rng.gen_range(0..ties.len())])
+    }
+}
+
+impl Solver for GreedySolver {
+    fn name(&self) -> &str {
+        "greedy"
+    }
+
+    fn solve(&mut self, set : &Set, config : &SolverConfig, rng : &mut dyn RngCore, observer : &mut dyn SolverObserver) -> SolverResult {
+        let start = Instant::now();
+
+        let mut cover = config.empty_cover(set);
+        let cancelled = observer.should_stop();
+        let mut stop_reason = StopReason::Cancelled;
+        if !cancelled {
+            cover.set_subsets_unchecked(GreedySolver::construct(set, config, rng), set);
+            // La construcción parte de la solución inicial, pero con otros modos puede no mejorarla
+            if let Some(initial) = config.initial.as_ref().filter(|initial| initial.cost < cover.cost) {
+                cover = initial.clone();
+            }
+            observer.on_new_best(0, &cover);
+            observer.on_iteration(0, &cover);
+
+            // Una sola construcción: solo tiene sentido reportar si se alcanzó la meta de costo
+            let progress = SearchProgress { iterations : 1, stagnation : 0, elapsed : start.elapsed(), best_cost : cover.cost };
+            stop_reason = config.termination.check(&progress)
+                .filter(|reason| matches!(reason, StopReason::TargetCost | StopReason::LowerBound))
+                .unwrap_or(StopReason::Completed);
+        }
+
+        let performed = if cancelled { 0 } else { 1 };
+        let stats = SolverStats {
+            solver : self.name().to_string(),
+            iterations : performed,
+            evaluations : performed,
+            best_iteration : 0,
+            elapsed_secs : start.elapsed().as_secs_f64(),
+            restarts : 0,
+            stop_reason,
+            feasible : cover.is_feasible(set)
+        };
+        observer.on_finish(&stats);
+        SolverResult { cover, stats }
+    }
+}
@@ -0,0 +1,256 @@
+use std::collections::BTreeSet;
+use std::fmt;
+use std::time::Instant;
+
+use rand::RngCore;
+use serde::Serialize;
+
+use super::greedy::GreedySolver;
+use super::termination::{SearchProgress, StopReason, Termination};
+use super::{Solver, SolverConfig, SolverObserver, SolverResult, SolverStats};
+use crate::entity::set::Set;
+use crate::entity::subset_cover::SubsetCover;
+
+// Mejora mínima del costo para aceptar un movimiento; evita ciclos por redondeo
+const MIN_IMPROVEMENT: f64 = 1e-12;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize)]
+pub enum Strategy {
+    // Aplica el primer movimiento que mejora
+    #[default]
+    FirstImprovement,
+    // Revisa toda la vecindad y aplica el movimiento que más mejora
+    BestImprovement
+}
+
+impl Strategy {
+    pub fn from_name(name : &str) -> Option<Self> {
+        match name {
+            "first" => Some(Strategy::FirstImprovement),
+            "best" => Some(Strategy::BestImprovement),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Strategy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Strategy::FirstImprovement => write!(f, "first"),
+            Strategy::BestImprovement => write!(f, "best"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Move {
+    Remove(usize),
+    // Sale un subconjunto y entra otro
+    Swap { out : usize, into : usize },
+    // Salen dos subconjuntos y entra uno
+    DoubleSwap { out : (usize, usize), into : usize }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize)]
+pub struct LocalSearchStats {
+    pub removals : usize,
+    pub swaps : usize,
+    pub double_swaps : usize,
+    pub evaluations : usize,
+    pub initial_cost : f64,
+    pub final_cost : f64,
+    pub stop_reason : StopReason
+}
+
+impl LocalSearchStats {
+    pub fn moves(&self) -> usize {
+        self.removals + self.swaps + self.double_swaps
+    }
+}
+
+impl fmt::Display for LocalSearchStats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} movimientos ({} eliminaciones, {} intercambios 1-1, {} intercambios 2-1), costo {:.6} -> {:.6}, criterio de paro: {}",
+            self.moves(), self.removals, self.swaps, self.double_swaps, self.initial_cost, self.final_cost, self.stop_reason
+        )
+    }
+}
+
+// Búsqueda local sobre una cobertura: quita subconjuntos, cambia uno por otro o dos por uno
+// mientras baje el costo de la función objetivo sin aumentar el faltante, hasta llegar a un
+// óptimo local. Los candidatos a entrar son los subconjuntos que comparten algún elemento con
+// los que salen. Cada movimiento aceptado cuenta como una iteración para los criterios de paro,
+// y el tiempo se mide desde que empieza el pulido.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LocalSearch {
+    pub strategy : Strategy
+}
+
+impl LocalSearch {
+    pub fn new(strategy : Strategy) -> Self {
+        LocalSearch { strategy }
+    }
+
+    pub fn polish(&self, cover : &SubsetCover, set : &Set, termination : &Termination, observer : &mut dyn SolverObserver) -> (SubsetCover, LocalSearchStats) {
+        let start = Instant::now();
+        let mut current = cover.clone();
+        let mut stats = LocalSearchStats { initial_cost : cover.cost, ..LocalSearchStats::default() };
+
+        stats.stop_reason = loop {
+            if observer.should_stop() {
+                break StopReason::Cancelled;
+            }
+            // Cada movimiento mejora, así que la búsqueda nunca se estanca
+            let progress = SearchProgress {
+                iterations : stats.moves(),
+                stagnation : 0,
+                elapsed : start.elapsed(),
+                best_cost : current.cost
+            };
+            if let Some(reason) = termination.check(&progress) {
+                break reason;
+            }
+
+            let Some((mov, subsets)) = self.find_move(&current, set, &mut stats) else {
+                break StopReason::Completed;
+            };
+            let iteration = stats.moves();
+            match mov {
+                Move::Remove(_) => stats.removals += 1,
+                Move::Swap { .. } => stats.swaps += 1,
+                Move::DoubleSwap { .. } => stats.double_swaps += 1,
+            }
+            current.set_subsets_unchecked(subsets, set);
+            observer.on_new_best(iteration, &current);
+            observer.on_iteration(iteration, &current);
+        };
+
+        stats.final_cost = current.cost;
+        (current, stats)
+    }
+
+    fn find_move(&self, cover : &SubsetCover, set : &Set, stats : &mut LocalSearchStats) -> Option<(Move, Vec<usize>)> {
+        let shortfall = |subsets : &[usize]| cover.mode.shortfall(subsets, set).unwrap_or(f64::INFINITY);
+        let current_shortfall = shortfall(&cover.elements);
+        let current_feasible = cover.mode.is_feasible(&cover.elements, set);
+        let selected = &cover.elements;
+
+        let mut best: Option<(Move, Vec<usize>, f64)> = None;
+        let mut consider = |mov : Move, stats : &mut LocalSearchStats| -> bool {
+            let trial = apply(selected, mov);
+            stats.evaluations += 1;
+            let cost = cover.objective.cost(&trial, set, &cover.mode);
+            let limit = best.as_ref().map_or(cover.cost, |(_, _, best_cost)| *best_cost);
+            let keeps_coverage = shortfall(&trial) <= current_shortfall
+                && (!current_feasible || cover.mode.is_feasible(&trial, set));
+            if cost < limit - MIN_IMPROVEMENT && keeps_coverage {
+                best = Some((mov, trial, cost));
+                return self.strategy == Strategy::FirstImprovement;
+            }
+            false
+        };
+
+        for &out in selected {
+            if consider(Move::Remove(out), stats) {
+                return best.map(|(mov, trial, _)| (mov, trial));
+            }
+        }
+        for &out in selected {
+            for into in candidates(set, selected, &[out]) {
+                if consider(Move::Swap { out, into }, stats) {
+                    return best.map(|(mov, trial, _)| (mov, trial));
+                }
+            }
+        }
+        for (position, &first) in selected.iter().enumerate() {
+            for &second in &selected[position + 1..] {
+                for into in candidates(set, selected, &[first, second]) {
+                    if consider(Move::DoubleSwap { out : (first, second), into }, stats) {
+                        return best.map(|(mov, trial, _)| (mov, trial));
+                    }
+                }
+            }
+        }
+        best.map(|(mov, trial, _)| (mov, trial))
+    }
+}
+
+// Subconjuntos fuera de la cobertura que comparten algún elemento con los que salen
+fn candidates(set : &Set, selected : &[usize], out : &[usize]) -> BTreeSet<usize> {
+    out.iter()
+        .flat_map(|&subset| set.elements_of(subset))
+        .flat_map(|&element| set.subsets_covering(element))
+        .copied()
+        .filter(|subset| !selected.contains(subset))
+        .collect()
+}
+
+fn apply(selected : &[usize], mov : Move) -> Vec<usize> {
+    let (out, into): (Vec<usize>, Option<usize>) = match mov {
+        Move::Remove(out) => (vec![out], None),
+        Move::Swap { out, into } => (vec![out], Some(into)),
+        Move::DoubleSwap { out : (first, second), into } => (vec![first, second], Some(into)),
+    };
+    let mut trial: Vec<usize> = selected.iter().copied().filter(|subset| !out.contains(subset)).collect();
+    trial.extend(into);
+    trial.sort_unstable();
+    trial
+}
+
+// Solver que solo pule: parte de la solución inicial (o de una construcción greedy si no hay)
+// y aplica la búsqueda local
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LocalSearchSolver {
+    pub search : LocalSearch
+}
+
+impl Solver for LocalSearchSolver {
+    fn name(&self) -> &str {
+        "local"
+    }
+
+    fn solve(&mut self, set : &Set, config : &SolverConfig, rng : &mut dyn RngCore, observer : &mut dyn SolverObserver) -> SolverResult {
+        let start = Instant::now();
+        if observer.should_stop() {
+            let cover = config.empty_cover(set);
+            let stats = SolverStats {
+                solver : self.name().to_string(),
+                iterations : 0,
+                evaluations : 0,
+                best_iteration : 0,
+                elapsed_secs : start.elapsed().as_secs_f64(),
+                restarts : 0,
+                stop_reason : StopReason::Cancelled,
+                feasible : cover.is_feasible(set)
+            };
+            observer.on_finish(&stats);
+            return SolverResult { cover, stats };
+        }
+
+        let initial = match &config.initial {
+            Some(initial) => initial.clone(),
+            None => {
+                let mut cover = config.empty_cover(set);
+                cover.set_subsets_unchecked(GreedySolver::construct(set, config, rng), set);
+                cover
+            }
+        };
+        observer.on_new_best(0, &initial);
+        let (cover, search) = self.search.polish(&initial, set, &config.termination, observer);
+
+        let stats = SolverStats {
+            solver : self.name().to_string(),
+            iterations : search.moves(),
+            evaluations : search.evaluations,
+            best_iteration : search.moves().saturating_sub(1),
+            elapsed_secs : start.elapsed().as_secs_f64(),
+            restarts : 0,
+            stop_reason : search.stop_reason,
+            feasible : cover.is_feasible(set)
+        };
+        observer.on_finish(&stats);
+        SolverResult { cover, stats }
+    }
+}
@@ -0,0 +1,190 @@
+pub mod checkpoint;
+pub mod greedy;
+pub mod local_search;
+pub mod rfd;
+pub mod registry;
+pub mod observer;
+pub mod termination;
+
+use std::fmt;
+use std::sync::Arc;
+
+use rand::RngCore;
+use serde::Serialize;
+
+use crate::entity::cover_mode::CoverMode;
+use crate::entity::objective::{DefaultObjective, Objective};
+use crate::entity::set::Set;
+use crate::entity::subset_cover::SubsetCover;
+use checkpoint::Checkpoint;
+use termination::{StopReason, Termination};
+
+// Parámetros comunes a todos los solvers. Cada solver usa los que le aplican.
+#[derive(Clone)]
+pub struct SolverConfig {
+    pub mode : CoverMode,
+    pub objective : Arc<dyn Objective>,
+    pub termination : Termination,
+    // Gotas por iteración en RFD
+    pub drops : usize,
+    // Fracción de altura que pierde un subconjunto de la mejor gota de la iteración
+    pub erosion : f64,
+    // Fracción de la diferencia con la altura inicial que recuperan todos los subconjuntos
+    pub sedimentation : f64,
+    // Iteraciones sin mejora tras las que RFD reinicia las alturas (0 = nunca)
+    pub restart_after : usize,
+    // Cada cuántas iteraciones se emite un punto de control (0 = nunca)
+    pub checkpoint_interval : usize,
+    // Solución de partida: los solvers la toman como la mejor conocida y construyen a partir de ella
+    pub initial : Option<SubsetCover>
+}
+
+impl Default for SolverConfig {
+    fn default() -> Self {
+        SolverConfig::new(CoverMode::Full, Arc::new(DefaultObjective))
+    }
+}
+
+impl SolverConfig {
+    pub fn new(mode : CoverMode, objective : Arc<dyn Objective>) -> Self {
+        SolverConfig {
+            mode,
+            objective,
+            termination : Termination::default(),
+            drops : 20,
+            erosion : 0.2,
+            sedimentation : 0.05,
+            restart_after : 0,
+            checkpoint_interval : 0,
+            initial : None
+        }
+    }
+
+    pub fn empty_cover(&self, set : &Set) -> SubsetCover {
+        SubsetCover::with_objective(set, self.mode.clone(), self.objective.clone())
+    }
+
+    // Valida una solución externa contra la instancia y la repara si no es factible. Devuelve
+    // la cobertura y si hizo falta repararla.
+    pub fn warm_start(&self, subsets : &[usize], set : &Set) -> Result<(SubsetCover, bool), String> {
+        set.check_subset_indices(subsets).map_err(|e| e.to_string())?;
+        let feasible = self.mode.is_feasible(subsets, set);
+        let subsets = if feasible { subsets.to_vec() } else { self.mode.repair(subsets, set) };
+
+        let mut cover = self.empty_cover(set);
+        cover.set_subsets_unchecked(subsets, set);
+        Ok((cover, !feasible))
+    }
+
+    pub fn with_initial(mut self, subsets : &[usize], set : &Set) -> Result<Self, String> {
+        self.initial = Some(self.warm_start(subsets, set)?.0);
+        Ok(self)
+    }
+
+    // Reutiliza una cobertura que ya pasó por warm_start, sin validarla ni repararla otra vez;
+    // solo se vuelve a evaluar con la función objetivo de esta configuración
+    pub fn with_initial_cover(mut self, cover : &SubsetCover, set : &Set) -> Self {
+        let mut initial = self.empty_cover(set);
+        initial.set_subsets_unchecked(cover.elements.clone(), set);
+        self.initial = Some(initial);
+        self
+    }
+}
+
+// Eventos que reportan los solvers mientras corren. Los solvers consultan should_stop antes de
+// cada iteración para permitir cancelar la búsqueda desde fuera.
+pub trait SolverObserver {
+    fn on_iteration(&mut self, _iteration : usize, _best : &SubsetCover) {}
+
+    fn on_new_best(&mut self, _iteration : usize, _best : &SubsetCover) {}
+
+    fn on_restart(&mut self, _iteration : usize) {}
+
+    fn on_checkpoint(&mut self, _checkpoint : &Checkpoint, _best : &SubsetCover) {}
+
+    fn on_finish(&mut self, _stats : &SolverStats) {}
+
+    fn should_stop(&self) -> bool {
+        false
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoopObserver;
+
+impl SolverObserver for NoopObserver {}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SolverStats {
+    pub solver : String,
+    pub iterations : usize,
+    pub evaluations : usize,
+    pub best_iteration : usize,
+    pub elapsed_secs : f64,
+    pub restarts : usize,
+    pub stop_reason : StopReason,
+    pub feasible : bool
+}
+
+impl SolverStats {
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap_or_else(|e| format!("{{\"error\": \"{}\"}}", e))
+    }
+}
+
+impl fmt::Display for SolverStats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "Estadísticas del solver ({})", self.solver)?;
+        writeln!(f, "----------------------------------")?;
+        writeln!(f, "| {:<38} | {:>12} |", "Iteraciones", self.iterations)?;
+        writeln!(f, "| {:<38} | {:>12} |", "Evaluaciones de la función objetivo", self.evaluations)?;
+        writeln!(f, "| {:<38} | {:>12} |", "Iteración de la mejor solución", self.best_iteration)?;
+        writeln!(f, "| {:<38} | {:>12.3} |", "Tiempo (s)", self.elapsed_secs)?;
+        writeln!(f, "| {:<38} | {:>12} |", "Reinicios", self.restarts)?;
+        writeln!(f, "| {:<38} | {:>12} |", "Factible", if self.feasible { "sí" } else { "no" })?;
+        writeln!(f, "----------------------------------")?;
+        writeln!(f, "Criterio de paro: {}", self.stop_reason)
+    }
+}
+
+#[derive(Clone)]
+pub struct SolverResult {
+    pub cover : SubsetCover,
+    pub stats : SolverStats
+}
+
+pub trait Solver {
+    fn name(&self) -> &str;
+
+    fn solve(&mut self, set : &Set, config : &SolverConfig, rng : &mut dyn RngCore, observer : &mut dyn SolverObserver) -> SolverResult;
+
+    // Prepara la siguiente llamada a solve para continuar desde un punto de control
+    fn resume_from(&mut self, _checkpoint : Checkpoint) -> Result<(), String> {
+        Err(format!("El solver {} no admite reanudar desde un punto de control", self.name()))
+    }
+}
+
+// Quita los subconjuntos que se pueden retirar sin aumentar el faltante ni empeorar la función
+// objetivo, empezando por los más caros y, entre ellos, los de mayor índice
+pub fn prune(subsets : &[usize], set : &Set, config : &SolverConfig) -> Vec<usize> {
+    let mut pruned: Vec<usize> = subsets.to_vec();
+    let mut order: Vec<usize> = subsets.to_vec();
+    order.sort_by(|&a, &b| set.get_subset_cost(b).total_cmp(&set.get_subset_cost(a)).then(b.cmp(&a)));
+
+    let evaluate = |subsets : &[usize]| (
+        config.mode.shortfall(subsets, set).unwrap_or(f64::INFINITY),
+        config.objective.cost(subsets, set, &config.mode)
+    );
+
+    let (mut shortfall, mut cost) = evaluate(&pruned);
+    for index in order {
+        let trial: Vec<usize> = pruned.iter().copied().filter(|&i| i != index).collect();
+        let (trial_shortfall, trial_cost) = evaluate(&trial);
+        if trial_shortfall <= shortfall && trial_cost <= cost {
+            pruned = trial;
+            shortfall = trial_shortfall;
+            cost = trial_cost;
+        }
+    }
+    pruned
+}
@@ -0,0 +1,250 @@
+use std::path::Path;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use serde::Serialize;
+use svg::Document;
+use svg::node::element::{Line, Polyline, Text};
+
+use super::checkpoint::Checkpoint;
+use super::{SolverObserver, SolverStats};
+use crate::entity::subset_cover::SubsetCover;
+
+const DEFAULT_LOG_INTERVAL: usize = 10;
+const PLOT_WIDTH: f64 = 640.0;
+const PLOT_HEIGHT: f64 = 360.0;
+const PLOT_MARGIN: f64 = 48.0;
+
+// Reenvía cada evento a varios observadores; la búsqueda se detiene si cualquiera lo pide
+#[derive(Default)]
+pub struct ObserverGroup<'a> {
+    pub observers : Vec<&'a mut dyn SolverObserver>
+}
+
+impl<'a> ObserverGroup<'a> {
+    pub fn new() -> Self {
+        ObserverGroup { observers : Vec::new() }
+    }
+
+    pub fn push(&mut self, observer : &'a mut dyn SolverObserver) {
+        self.observers.push(observer);
+    }
+}
+
+impl SolverObserver for ObserverGroup<'_> {
+    fn on_iteration(&mut self, iteration : usize, best : &SubsetCover) {
+        self.observers.iter_mut().for_each(|observer| observer.on_iteration(iteration, best));
+    }
+
+    fn on_new_best(&mut self, iteration : usize, best : &SubsetCover) {
+        self.observers.iter_mut().for_each(|observer| observer.on_new_best(iteration, best));
+    }
+
+    fn on_restart(&mut self, iteration : usize) {
+        self.observers.iter_mut().for_each(|observer| observer.on_restart(iteration));
+    }
+
+    fn on_checkpoint(&mut self, checkpoint : &Checkpoint, best : &SubsetCover) {
+        self.observers.iter_mut().for_each(|observer| observer.on_checkpoint(checkpoint, best));
+    }
+
+    fn on_finish(&mut self, stats : &SolverStats) {
+        self.observers.iter_mut().for_each(|observer| observer.on_finish(stats));
+    }
+
+    fn should_stop(&self) -> bool {
+        self.observers.iter().any(|observer| observer.should_stop())
+    }
+}
+
+// Bitácora en consola para el modo verbose; sin él no imprime nada
+#[derive(Debug, Clone, Copy)]
+pub struct ConsoleObserver {
+    pub enabled : bool,
+    // Cada cuántas iteraciones se imprime el estado de la búsqueda
+    pub interval : usize
+}
+
+impl ConsoleObserver {
+    pub fn new(enabled : bool) -> Self {
+        ConsoleObserver { enabled, interval : DEFAULT_LOG_INTERVAL }
+    }
+}
+
+impl SolverObserver for ConsoleObserver {
+    fn on_iteration(&mut self, iteration : usize, best : &SubsetCover) {
+        if self.enabled && (iteration + 1).is_multiple_of(self.interval.max(1)) {
+            println!("Iteración {}: costo {:.6}, {} subconjuntos", iteration + 1, best.cost, best.size);
+        }
+    }
+
+    fn on_new_best(&mut self, iteration : usize, best : &SubsetCover) {
+        if self.enabled {
+            println!("Nueva mejor solución en la iteración {}: costo {:.6}, {} subconjuntos", iteration + 1, best.cost, best.size);
+        }
+    }
+
+    fn on_restart(&mut self, iteration : usize) {
+        if self.enabled {
+            println!("Reinicio en la iteración {}", iteration + 1);
+        }
+    }
+
+    fn on_checkpoint(&mut self, checkpoint : &Checkpoint, _best : &SubsetCover) {
+        if self.enabled {
+            println!("Punto de control en la iteración {}", checkpoint.iteration);
+        }
+    }
+
+    fn on_finish(&mut self, stats : &SolverStats) {
+        if self.enabled {
+            println!("Búsqueda terminada ({}) tras {} iteraciones ({:.3} s)", stats.stop_reason, stats.iterations, stats.elapsed_secs);
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct TracePoint {
+    pub iteration : usize,
+    pub cost : f64,
+    pub size : usize
+}
+
+impl TracePoint {
+    fn from_cover(iteration : usize, best : &SubsetCover) -> Self {
+        TracePoint { iteration, cost : best.cost, size : best.size }
+    }
+}
+
+// Registra la mejor solución de cada iteración para graficar la convergencia
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct TraceObserver {
+    pub points : Vec<TracePoint>,
+    pub improvements : Vec<TracePoint>,
+    pub restarts : Vec<usize>
+}
+
+impl TraceObserver {
+    pub fn new() -> Self {
+        TraceObserver::default()
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap_or_else(|e| format!("{{\"error\": \"{}\"}}", e))
+    }
+
+    // Curva de costo contra iteración, con líneas verticales en los reinicios
+    pub fn to_svg(&self) -> Document {
+        let last_iteration = self.points.last().map_or(1, |point| point.iteration.max(1)) as f64;
+        let finite_costs = || self.points.iter().map(|point| point.cost).filter(|cost| cost.is_finite());
+        let max_cost = finite_costs().fold(f64::MIN, f64::max);
+        let min_cost = finite_costs().fold(f64::MAX, f64::min);
+        let range = if max_cost > min_cost { max_cost - min_cost } else { 1.0 };
+
+        let inner_width = PLOT_WIDTH - 2.0 * PLOT_MARGIN;
+        let inner_height = PLOT_HEIGHT - 2.0 * PLOT_MARGIN;
+        let x = |iteration : usize| PLOT_MARGIN + inner_width * iteration as f64 / last_iteration;
+        let y = |cost : f64| PLOT_MARGIN + inner_height * (max_cost - cost) / range;
+
+        let points: Vec<String> = self.points.iter()
+            .filter(|point| point.cost.is_finite())
+            .map(|point| format!("{:.2},{:.2}", x(point.iteration), y(point.cost)))
+            .collect();
+
+        let mut document = Document::new()
+            .set("viewBox", (0, 0, PLOT_WIDTH, PLOT_HEIGHT))
+            .set("width", PLOT_WIDTH)
+            .set("height", PLOT_HEIGHT)
+            .add(axis(PLOT_MARGIN, PLOT_HEIGHT - PLOT_MARGIN, PLOT_WIDTH - PLOT_MARGIN, PLOT_HEIGHT - PLOT_MARGIN))
+            .add(axis(PLOT_MARGIN, PLOT_MARGIN, PLOT_MARGIN, PLOT_HEIGHT - PLOT_MARGIN))
+            .add(label(PLOT_WIDTH / 2.0, PLOT_HEIGHT - PLOT_MARGIN / 4.0, "Iteración"))
+            .add(label(PLOT_MARGIN, PLOT_MARGIN / 2.0, "Costo"));
+
+        if min_cost <= max_cost {
+            document = document
+                .add(label(PLOT_MARGIN / 2.0, y(max_cost), &format!("{:.3}", max_cost)))
+                .add(label(PLOT_MARGIN / 2.0, y(min_cost), &format!("{:.3}", min_cost)));
+        }
+
+        for &iteration in &self.restarts {
+            document = document.add(
+                axis(x(iteration), PLOT_MARGIN, x(iteration), PLOT_HEIGHT - PLOT_MARGIN)
+                    .set("stroke", "gray")
+                    .set("stroke-dasharray", "4 4")
+            );
+        }
+
+        document.add(
+            Polyline::new()
+                .set("fill", "none")
+                .set("stroke", "steelblue")
+                .set("stroke-width", 2)
+                .set("points", points.join(" "))
+        )
+    }
+
+    pub fn save_svg<P : AsRef<Path>>(&self, path : P) -> std::io::Result<()> {
+        svg::save(path, &self.to_svg())
+    }
+}
+
+fn axis(x1 : f64, y1 : f64, x2 : f64, y2 : f64) -> Line {
+    Line::new()
+        .set("x1", x1)
+        .set("y1", y1)
+        .set("x2", x2)
+        .set("y2", y2)
+        .set("stroke", "black")
+}
+
+fn label(x : f64, y : f64, content : &str) -> Text {
+    Text::new(content)
+        .set("x", x)
+        .set("y", y)
+        .set("font-size", 12)
+        .set("text-anchor", "middle")
+}
+
+impl SolverObserver for TraceObserver {
+    fn on_iteration(&mut self, iteration : usize, best : &SubsetCover) {
+        self.points.push(TracePoint::from_cover(iteration, best));
+    }
+
+    fn on_new_best(&mut self, iteration : usize, best : &SubsetCover) {
+        self.improvements.push(TracePoint::from_cover(iteration, best));
+    }
+
+    fn on_restart(&mut self, iteration : usize) {
+        self.restarts.push(iteration);
+    }
+}
+
+// Permite detener una búsqueda desde otro hilo: basta con llamar a cancel sobre un clon
+#[derive(Debug, Clone, Default)]
+pub struct CancelObserver {
+    pub flag : Arc<AtomicBool>
+}
+
+impl CancelObserver {
+    pub fn new() -> Self {
+        CancelObserver::default()
+    }
+
+    pub fn with_flag(flag : Arc<AtomicBool>) -> Self {
+        CancelObserver { flag }
+    }
+
+    pub fn cancel(&self) {
+        self.flag.store(true, Ordering::SeqCst);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.flag.load(Ordering::SeqCst)
+    }
+}
+
+impl SolverObserver for CancelObserver {
+    fn should_stop(&self) -> bool {
+        self.is_cancelled()
+    }
+}
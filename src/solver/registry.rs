@@ -0,0 +1,43 @@
+use std::collections::BTreeMap;
+
+use super::greedy::GreedySolver;
+use super::local_search::LocalSearchSolver;
+use super::rfd::RfdSolver;
+use super::Solver;
+
+pub type SolverFactory = Box<dyn Fn() -> Box<dyn Solver> + Send + Sync>;
+
+// Solvers disponibles por nombre. Las aplicaciones que usan la biblioteca pueden registrar
+// los suyos junto a los incluidos.
+pub struct SolverRegistry {
+    pub factories : BTreeMap<String, SolverFactory>
+}
+
+impl Default for SolverRegistry {
+    fn default() -> Self {
+        let mut registry = SolverRegistry::empty();
+        registry.register("greedy", || Box::new(GreedySolver));
+        registry.register("rfd", || Box::new(RfdSolver::new()));
+        registry.register("local", || Box::new(LocalSearchSolver::default()));
+        registry
+    }
+}
+
+impl SolverRegistry {
+    pub fn empty() -> Self {
+        SolverRegistry { factories : BTreeMap::new() }
+    }
+
+    // Registra un solver; si el nombre ya existía se reemplaza
+    pub fn register(&mut self, name : &str, factory : impl Fn() -> Box<dyn Solver> + Send + Sync + 'static) {
+        self.factories.insert(name.to_string(), Box::new(factory));
+    }
+
+    pub fn create(&self, name : &str) -> Option<Box<dyn Solver>> {
+        self.factories.get(name).map(|factory| factory())
+    }
+
+    pub fn names(&self) -> Vec<&str> {
+        self.factories.keys().map(|name| name.as_str()).collect()
+    }
+}
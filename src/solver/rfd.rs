@@ -0,0 +1,289 @@
+use std::time::{Duration, Instant};
+
+use rand::{Rng, RngCore, SeedableRng};
+use rand_chacha::ChaCha12Rng;
+
+use super::checkpoint::{Checkpoint, RngState};
+use super::termination::{SearchProgress, StopReason};
+use super::{prune, Solver, SolverConfig, SolverObserver, SolverResult, SolverStats};
+use crate::entity::cover_mode::CoverMode;
+use crate::entity::set::Set;
+use crate::entity::subset_cover::SubsetCover;
+
+const SOURCE_ALTITUDE: f64 = 1.0;
+const MIN_ALTITUDE: f64 = 0.01;
+// Pendiente que se asigna a los movimientos planos o de subida para que sigan siendo posibles
+const FLAT_SLOPE: f64 = 0.01;
+
+// River Formation Dynamics: cada subconjunto tiene una altura. Las gotas parten del manantial
+// (altura 1) y agregan subconjuntos con probabilidad proporcional a la pendiente hacia ellos
+// por la reducción del faltante por unidad de costo. Al final de cada iteración la mejor gota
+// erosiona su camino, que así atrae a las siguientes, y la sedimentación devuelve poco a poco
+// todas las alturas hacia la del manantial.
+#[derive(Debug, Clone, Default)]
+pub struct RfdSolver {
+    pub altitudes : Vec<f64>,
+    // Punto de control desde el que continuará la siguiente llamada a solve
+    pub resume : Option<Checkpoint>
+}
+
+impl RfdSolver {
+    pub fn new() -> Self {
+        RfdSolver::default()
+    }
+
+    // Camino de una gota: la familia de subconjuntos que va agregando hasta ser factible
+    pub fn drop_path(&self, set : &Set, config : &SolverConfig, rng : &mut dyn RngCore) -> Vec<usize> {
+        let keeps_flowing = |selected : &[usize], counts : &[usize]| {
+            matches!(config.mode, CoverMode::MaxCoverage(_)) || !config.mode.is_feasible_with_counts(selected, counts, set)
+        };
+
+        // Las multiplicidades y los pesos de la ruleta se reutilizan en todo el camino
+        let mut selected: Vec<usize> = Vec::new();
+        let mut counts: Vec<usize> = vec![0; set.get_size_set()];
+        let mut weights: Vec<f64> = Vec::new();
+        let mut altitude = SOURCE_ALTITUDE;
+        while keeps_flowing(&selected, &counts) {
+            config.mode.marginal_gains_into(&selected, &counts, set, &mut weights);
+            for (index, weight) in weights.iter_mut().enumerate() {
+                *weight = if *weight <= 0.0 {
+                    0.0
+                } else {
+                    let slope = (altitude - self.altitudes[index]).max(FLAT_SLOPE);
+                    slope * *weight / set.get_subset_cost(index).max(f64::EPSILON)
+                };
+            }
+
+            match RfdSolver::roulette(&weights, rng) {
+                Some(index) => {
+                    selected.push(index);
+                    set.add_coverage(index, &mut counts);
+                    altitude = self.altitudes[index];
+                }
+                None => break,
+            }
+        }
+
+        if !config.mode.is_feasible(&selected, set) {
+            selected = config.mode.repair(&selected, set);
+        }
+        prune(&selected, set, config)
+    }
+
+    fn checkpoint(&self, state : &RunState, best : &SubsetCover, set : &Set, config : &SolverConfig, elapsed : Duration) -> Checkpoint {
+        Checkpoint {
+            solver : self.name().to_string(),
+            mode : config.mode.to_string(),
+            objective : config.objective.name().to_string(),
+            size_set : set.get_size_set(),
+            size_subsets : set.get_size_subsets(),
+            iteration : state.performed,
+            altitudes : self.altitudes.clone(),
+            best : best.elements.clone(),
+            best_cost : state.best_cost,
+            best_cover_cost : best.cost,
+            best_iteration : state.best_iteration,
+            evaluations : state.evaluations,
+            restarts : state.restarts,
+            stagnation : state.stagnation,
+            since_restart : state.since_restart,
+            elapsed_secs : elapsed.as_secs_f64(),
+            objective_state : config.objective.state(),
+            rng : RngState::capture(&state.rng)
+        }
+    }
+
+    pub fn erode(&mut self, path : &[usize], config : &SolverConfig) {
+        for &index in path {
+            self.altitudes[index] = (self.altitudes[index] * (1.0 - config.erosion)).max(MIN_ALTITUDE);
+        }
+    }
+
+    pub fn sediment(&mut self, config : &SolverConfig) {
+        for altitude in self.altitudes.iter_mut() {
+            *altitude += config.sedimentation * (SOURCE_ALTITUDE - *altitude);
+        }
+    }
+
+    fn roulette(weights : &[f64], rng : &mut dyn RngCore) -> Option<usize> {
+        let total: f64 = weights.iter().sum();
+        if total <= 0.0 || !total.is_finite() {
+            return None;
+        }
+
+        let mut target = rng.gen_range(0.0..total);
+        for (index, &weight) in weights.iter().enumerate() {
+            if weight <= 0.0 {
+                continue;
+            }
+            if target < weight {
+                return Some(index);
+            }
+            target -= weight;
+        }
+        weights.iter().rposition(|&weight| weight > 0.0)
+    }
+}
+
+impl Solver for RfdSolver {
+    fn name(&self) -> &str {
+        "rfd"
+    }
+
+    fn solve(&mut self, set : &Set, config : &SolverConfig, rng : &mut dyn RngCore, observer : &mut dyn SolverObserver) -> SolverResult {
+        let start = Instant::now();
+        let mut best = config.empty_cover(set);
+
+        // La búsqueda usa su propio ChaCha, derivado del generador recibido, para poder guardar
+        // su posición exacta en los puntos de control
+        let mut state = match self.resume.take() {
+            Some(checkpoint) => {
+                config.objective.restore(&checkpoint.objective_state);
+                best.set_subsets_unchecked(checkpoint.best.clone(), set);
+                best.cost = checkpoint.best_cover_cost;
+                self.altitudes = checkpoint.altitudes.clone();
+                RunState::from_checkpoint(checkpoint)
+            }
+            None => {
+                self.altitudes = vec![SOURCE_ALTITUDE; set.get_size_subsets()];
+                let mut state = RunState::new(ChaCha12Rng::from_seed(rng.r#gen()));
+                // La solución inicial es la mejor conocida y su camino ya viene erosionado
+                if let Some(initial) = &config.initial {
+                    best = initial.clone();
+                    state.best_cost = initial.cost;
+                    self.erode(&initial.elements, config);
+                    observer.on_new_best(0, &best);
+                }
+                state
+            }
+        };
+        let elapsed = |state : &RunState| state.elapsed_before + start.elapsed();
+        let termination = config.termination.bounded();
+
+        let stop_reason = loop {
+            if observer.should_stop() {
+                break StopReason::Cancelled;
+            }
+            let progress = SearchProgress {
+                iterations : state.performed,
+                stagnation : state.stagnation,
+                elapsed : elapsed(&state),
+                best_cost : state.best_cost
+            };
+            if let Some(reason) = termination.check(&progress) {
+                break reason;
+            }
+
+            let iteration = state.performed;
+            let mut iteration_best: Option<(Vec<usize>, f64)> = None;
+            for _ in 0..config.drops.max(1) {
+                let path = self.drop_path(set, config, &mut state.rng);
+                let cost = config.objective.cost(&path, set, &config.mode);
+                state.evaluations += 1;
+                config.objective.update(config.mode.is_feasible(&path, set));
+
+                if iteration_best.as_ref().is_none_or(|(_, best)| cost < *best) {
+                    iteration_best = Some((path, cost));
+                }
+            }
+
+            state.stagnation += 1;
+            state.since_restart += 1;
+            if let Some((path, cost)) = iteration_best {
+                self.erode(&path, config);
+                if cost < state.best_cost {
+                    state.best_cost = cost;
+                    state.best_iteration = iteration;
+                    state.stagnation = 0;
+                    state.since_restart = 0;
+                    best.set_subsets_unchecked(path, set);
+                    observer.on_new_best(iteration, &best);
+                }
+            }
+            self.sediment(config);
+
+            // Sin mejoras en mucho tiempo el paisaje ya no guía a las gotas: se vuelve a aplanar
+            if config.restart_after > 0 && state.since_restart >= config.restart_after {
+                self.altitudes.fill(SOURCE_ALTITUDE);
+                state.since_restart = 0;
+                state.restarts += 1;
+                observer.on_restart(iteration);
+            }
+            state.performed += 1;
+            observer.on_iteration(iteration, &best);
+
+            if config.checkpoint_interval > 0 && state.performed.is_multiple_of(config.checkpoint_interval) {
+                observer.on_checkpoint(&self.checkpoint(&state, &best, set, config, elapsed(&state)), &best);
+            }
+        };
+
+        // Al detenerse por cualquier otro motivo se guarda el estado final para poder continuar
+        if config.checkpoint_interval > 0 && !state.performed.is_multiple_of(config.checkpoint_interval) {
+            observer.on_checkpoint(&self.checkpoint(&state, &best, set, config, elapsed(&state)), &best);
+        }
+
+        let stats = SolverStats {
+            solver : self.name().to_string(),
+            iterations : state.performed,
+            evaluations : state.evaluations,
+            best_iteration : state.best_iteration,
+            elapsed_secs : elapsed(&state).as_secs_f64(),
+            restarts : state.restarts,
+            stop_reason,
+            feasible : best.is_feasible(set)
+        };
+        observer.on_finish(&stats);
+        SolverResult { cover : best, stats }
+    }
+
+    fn resume_from(&mut self, checkpoint : Checkpoint) -> Result<(), String> {
+        if checkpoint.solver != self.name() {
+            return Err(format!("El punto de control es del solver {}, no de {}", checkpoint.solver, self.name()));
+        }
+        self.resume = Some(checkpoint);
+        Ok(())
+    }
+}
+
+// Contadores de una corrida que se guardan en los puntos de control junto con las alturas
+struct RunState {
+    rng : ChaCha12Rng,
+    performed : usize,
+    evaluations : usize,
+    restarts : usize,
+    stagnation : usize,
+    since_restart : usize,
+    best_cost : f64,
+    best_iteration : usize,
+    elapsed_before : Duration
+}
+
+impl RunState {
+    fn new(rng : ChaCha12Rng) -> Self {
+        RunState {
+            rng,
+            performed : 0,
+            evaluations : 0,
+            restarts : 0,
+            stagnation : 0,
+            since_restart : 0,
+            best_cost : f64::INFINITY,
+            best_iteration : 0,
+            elapsed_before : Duration::ZERO
+        }
+    }
+
+    fn from_checkpoint(checkpoint : Checkpoint) -> Self {
+        RunState {
+            rng : checkpoint.rng.restore(),
+            performed : checkpoint.iteration,
+            evaluations : checkpoint.evaluations,
+            restarts : checkpoint.restarts,
+            stagnation : checkpoint.stagnation,
+            since_restart : checkpoint.since_restart,
+            best_cost : checkpoint.best_cost,
+            best_iteration : checkpoint.best_iteration,
+            elapsed_before : Duration::from_secs_f64(checkpoint.elapsed_secs.max(0.0))
+        }
+    }
+}
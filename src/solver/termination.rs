@@ -0,0 +1,130 @@
+use std::fmt;
+use std::time::Duration;
+
+use serde::Serialize;
+
+pub const DEFAULT_MAX_ITERATIONS: usize = 200;
+// Holgura para comparar costos contra la meta y la cota inferior
+const COST_TOLERANCE: f64 = 1e-9;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize)]
+pub enum StopReason {
+    // El solver terminó por sí solo (por ejemplo, greedy tras una construcción)
+    #[default]
+    Completed,
+    TimeLimit,
+    MaxIterations,
+    Stagnation,
+    TargetCost,
+    LowerBound,
+    Cancelled
+}
+
+impl fmt::Display for StopReason {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let text = match self {
+            StopReason::Completed => "completada",
+            StopReason::TimeLimit => "límite de tiempo",
+            StopReason::MaxIterations => "máximo de iteraciones",
+            StopReason::Stagnation => "iteraciones sin mejora",
+            StopReason::TargetCost => "costo objetivo alcanzado",
+            StopReason::LowerBound => "cota inferior alcanzada",
+            StopReason::Cancelled => "cancelada",
+        };
+        write!(f, "{}", text)
+    }
+}
+
+// Estado de la búsqueda que se compara contra los criterios entre iteración e iteración
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SearchProgress {
+    pub iterations : usize,
+    pub stagnation : usize,
+    pub elapsed : Duration,
+    pub best_cost : f64
+}
+
+// Criterios de paro combinables: la búsqueda termina en cuanto se cumple cualquiera de ellos
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Termination {
+    pub time_limit : Option<Duration>,
+    pub max_iterations : Option<usize>,
+    pub max_stagnation : Option<usize>,
+    pub target_cost : Option<f64>,
+    pub lower_bound : Option<f64>
+}
+
+impl Default for Termination {
+    fn default() -> Self {
+        Termination::none().with_max_iterations(DEFAULT_MAX_ITERATIONS)
+    }
+}
+
+impl Termination {
+    pub fn none() -> Self {
+        Termination {
+            time_limit : None,
+            max_iterations : None,
+            max_stagnation : None,
+            target_cost : None,
+            lower_bound : None
+        }
+    }
+
+    pub fn with_time_limit(mut self, limit : Duration) -> Self {
+        self.time_limit = Some(limit);
+        self
+    }
+
+    pub fn with_max_iterations(mut self, iterations : usize) -> Self {
+        self.max_iterations = Some(iterations);
+        self
+    }
+
+    pub fn with_max_stagnation(mut self, iterations : usize) -> Self {
+        self.max_stagnation = Some(iterations);
+        self
+    }
+
+    pub fn with_target_cost(mut self, cost : f64) -> Self {
+        self.target_cost = Some(cost);
+        self
+    }
+
+    pub fn with_lower_bound(mut self, bound : f64) -> Self {
+        self.lower_bound = Some(bound);
+        self
+    }
+
+    // Una meta de costo o una cota pueden no alcanzarse nunca; solo el tiempo y las
+    // iteraciones garantizan que la búsqueda termine
+    pub fn is_bounded(&self) -> bool {
+        self.time_limit.is_some() || self.max_iterations.is_some() || self.max_stagnation.is_some()
+    }
+
+    // Sin criterios que garanticen el final se agrega el máximo de iteraciones por defecto
+    pub fn bounded(&self) -> Self {
+        if self.is_bounded() { *self } else { self.with_max_iterations(DEFAULT_MAX_ITERATIONS) }
+    }
+
+    // Primer criterio cumplido. Las metas de costo se revisan antes que los límites porque
+    // explican mejor por qué terminó la búsqueda.
+    pub fn check(&self, progress : &SearchProgress) -> Option<StopReason> {
+        if self.lower_bound.is_some_and(|bound| progress.best_cost <= bound + COST_TOLERANCE) {
+            return Some(StopReason::LowerBound);
+        }
+        if self.target_cost.is_some_and(|target| progress.best_cost <= target + COST_TOLERANCE) {
+            return Some(StopReason::TargetCost);
+        }
+        if self.max_iterations.is_some_and(|limit| progress.iterations >= limit) {
+            return Some(StopReason::MaxIterations);
+        }
+        if self.max_stagnation.is_some_and(|limit| progress.stagnation >= limit) {
+            return Some(StopReason::Stagnation);
+        }
+        if self.time_limit.is_some_and(|limit| progress.elapsed >= limit) {
+            return Some(StopReason::TimeLimit);
+        }
+        None
+    }
+}
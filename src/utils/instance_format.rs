@@ -0,0 +1,771 @@
+use std::cell::RefCell;
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
+use std::fmt;
+use std::fs::File;
+use std::io::{BufRead, BufWriter, Read, Write};
+use std::rc::Rc;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::entity::set::Set;
+use crate::entity::set_builder::SetBuilder;
+use super::read_input::{InputError, Location};
+
+// Cantidad de números por línea al escribir en formato OR-Library
+const ORLIB_NUMBERS_PER_LINE: usize = 12;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum InstanceFormat {
+    // Un subconjunto por línea separado por comas, con encabezados #universe, #demand, #cost y #weight
+    #[default]
+    Txt,
+    // Formato de la OR-Library de Beasley: filas (elementos), columnas (subconjuntos), costos y
+    // para cada fila las columnas que la cubren, con índices desde 1
+    OrLibrary,
+    // Objeto JSON con subconjuntos con nombre, etiquetas, costos, pesos, demandas y metadatos
+    Json,
+    // Lista de aristas subconjunto,elemento[,peso], una por fila; los costos van en una tabla aparte
+    Csv
+}
+
+impl InstanceFormat {
+    pub fn from_name(name : &str) -> Option<Self> {
+        match name {
+            "txt" => Some(InstanceFormat::Txt),
+            "orlib" => Some(InstanceFormat::OrLibrary),
+            "json" => Some(InstanceFormat::Json),
+            "csv" => Some(InstanceFormat::Csv),
+            _ => None,
+        }
+    }
+
+    // Extensión que se espera en el archivo de -c (con .gz opcional)
+    pub fn extension(&self) -> &'static str {
+        match self {
+            InstanceFormat::Txt | InstanceFormat::OrLibrary => "txt",
+            InstanceFormat::Json => "json",
+            InstanceFormat::Csv => "csv",
+        }
+    }
+}
+
+impl fmt::Display for InstanceFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            InstanceFormat::Txt => write!(f, "txt"),
+            InstanceFormat::OrLibrary => write!(f, "orlib"),
+            InstanceFormat::Json => write!(f, "json"),
+            InstanceFormat::Csv => write!(f, "csv"),
+        }
+    }
+}
+
+// Información de la instancia que el formato de destino no puede guardar
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConversionWarning {
+    ElementLabels { format : InstanceFormat },
+    ElementWeights { format : InstanceFormat, count : usize },
+    Demands { format : InstanceFormat, count : usize },
+    NonIntegerCosts { format : InstanceFormat, count : usize },
+    SubsetNames { format : InstanceFormat, count : usize },
+    SubsetAttributes { format : InstanceFormat, count : usize },
+    Metadata { format : InstanceFormat, count : usize },
+    SubsetCosts { format : InstanceFormat, count : usize },
+    ElementsWithoutSubsets { format : InstanceFormat, count : usize }
+}
+
+impl fmt::Display for ConversionWarning {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ConversionWarning::ElementLabels { format } => {
+                write!(f, "El formato {} no guarda etiquetas: los elementos se renumeran desde 1", format)
+            }
+            ConversionWarning::ElementWeights { format, count } => {
+                write!(f, "El formato {} no guarda pesos de elementos: se pierden {} pesos", format, count)
+            }
+            ConversionWarning::Demands { format, count } => {
+                write!(f, "El formato {} no guarda demandas: se pierden {} demandas", format, count)
+            }
+            ConversionWarning::NonIntegerCosts { format, count } => {
+                write!(f, "{} costos no son enteros; otras herramientas del formato {} pueden no leerlos", count, format)
+            }
+            ConversionWarning::SubsetNames { format, count } => {
+                write!(f, "El formato {} no guarda nombres de subconjuntos: se pierden {} nombres", format, count)
+            }
+            ConversionWarning::SubsetAttributes { format, count } => {
+                write!(f, "El formato {} no guarda atributos de subconjuntos: se pierden los de {} subconjuntos", format, count)
+            }
+            ConversionWarning::Metadata { format, count } => {
+                write!(f, "El formato {} no guarda metadatos: se pierden {} campos", format, count)
+            }
+            ConversionWarning::SubsetCosts { format, count } => {
+                write!(f, "El formato {} no guarda costos de subconjuntos: se pierden {} costos", format, count)
+            }
+            ConversionWarning::ElementsWithoutSubsets { format, count } => {
+                write!(f, "El formato {} solo guarda elementos de algún subconjunto: se pierden {} elementos", format, count)
+            }
+        }
+    }
+}
+
+// Subconjuntos y costos leídos de un archivo OR-Library. Los elementos son las filas "1".."m".
+#[derive(Debug)]
+pub struct OrLibraryInstance {
+    pub builder : SetBuilder,
+    pub costs : Vec<(usize, f64)>
+}
+
+pub fn read_orlib<R : BufRead>(reader : R) -> Result<OrLibraryInstance, InputError> {
+    let mut tokens = Tokens::new(reader);
+    let rows = tokens.next_number::<usize>("número de filas")?;
+    let columns = tokens.next_number::<usize>("número de columnas")?;
+
+    let mut costs: Vec<(usize, f64)> = Vec::with_capacity(columns);
+    for column in 0..columns {
+        let cost = tokens.next_number::<f64>("costo de columna")?;
+        if !cost.is_finite() || cost < 0.0 {
+            return Err(InputError::validation(Location::line(tokens.line), format!("costo inválido {}", cost)));
+        }
+        costs.push((column, cost));
+    }
+
+    // Las filas listan sus columnas; el Set se arma por columnas
+    let labels: Vec<String> = (1..=rows).map(|row| row.to_string()).collect();
+    let mut subsets: Vec<Vec<&str>> = vec![Vec::new(); columns];
+    for label in &labels {
+        let count = tokens.next_number::<usize>("cantidad de columnas de la fila")?;
+        for _ in 0..count {
+            let column = tokens.next_number::<usize>("columna")?;
+            if column == 0 || column > columns {
+                return Err(InputError::validation(
+                    Location::line(tokens.line), format!("columna {} fuera de rango (1..{})", column, columns)
+                ));
+            }
+            subsets[column - 1].push(label);
+        }
+    }
+    if let Some(extra) = tokens.next_token()? {
+        return Err(InputError::parse(Location::line(tokens.line), format!("dato sobrante '{}'", extra)));
+    }
+
+    let mut builder = SetBuilder::with_universe(&labels);
+    for subset in &subsets {
+        builder.add_subset(subset);
+    }
+    Ok(OrLibraryInstance { builder, costs })
+}
+
+// Etiqueta de un elemento en JSON: se aceptan cadenas y enteros
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+enum JsonLabel {
+    Text(String),
+    Number(u64)
+}
+
+impl JsonLabel {
+    fn into_string(self) -> String {
+        match self {
+            JsonLabel::Text(label) => label,
+            JsonLabel::Number(label) => label.to_string(),
+        }
+    }
+}
+
+// Los campos que no son name, elements ni cost son atributos libres del subconjunto
+#[derive(Debug, Serialize, Deserialize)]
+struct JsonSubset {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    name : Option<String>,
+    elements : Vec<JsonLabel>,
+    #[serde(default, skip_serializing_if = "Option::is_none", deserialize_with = "deserialize_cost")]
+    cost : Option<f64>,
+    #[serde(flatten)]
+    attributes : BTreeMap<String, Value>
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct JsonDocument {
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    metadata : BTreeMap<String, Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    universe : Option<Vec<JsonLabel>>,
+    subsets : Vec<JsonSubset>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    demands : BTreeMap<String, usize>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty", deserialize_with = "deserialize_weights")]
+    weights : BTreeMap<String, f64>
+}
+
+fn is_valid_amount(value : f64) -> bool {
+    value.is_finite() && value >= 0.0
+}
+
+// Los costos y pesos se validan al deserializar para que serde_json indique la línea y columna
+fn deserialize_cost<'de, D : serde::Deserializer<'de>>(deserializer : D) -> Result<Option<f64>, D::Error> {
+    match Option::<f64>::deserialize(deserializer)? {
+        Some(cost) if !is_valid_amount(cost) => Err(serde::de::Error::custom(format!("costo inválido {}", cost))),
+        cost => Ok(cost),
+    }
+}
+
+fn deserialize_weights<'de, D : serde::Deserializer<'de>>(deserializer : D) -> Result<BTreeMap<String, f64>, D::Error> {
+    struct WeightsVisitor;
+
+    impl<'de> serde::de::Visitor<'de> for WeightsVisitor {
+        type Value = BTreeMap<String, f64>;
+
+        fn expecting(&self, f : &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "un objeto elemento: peso")
+        }
+
+        fn visit_map<A : serde::de::MapAccess<'de>>(self, mut map : A) -> Result<Self::Value, A::Error> {
+            let mut weights = BTreeMap::new();
+            while let Some((element, weight)) = map.next_entry::<String, f64>()? {
+                if !is_valid_amount(weight) {
+                    return Err(serde::de::Error::custom(format!("peso inválido {} para el elemento {}", weight, element)));
+                }
+                weights.insert(element, weight);
+            }
+            Ok(weights)
+        }
+    }
+
+    deserializer.deserialize_map(WeightsVisitor)
+}
+
+// Instancia leída de un archivo JSON; los encabezados se aplican al Set igual que los de txt
+#[derive(Debug)]
+pub struct JsonInstance {
+    pub builder : SetBuilder,
+    pub universe : Option<Vec<String>>,
+    pub costs : Vec<(usize, f64)>,
+    pub names : Vec<(usize, String)>,
+    pub attributes : Vec<(usize, BTreeMap<String, Value>)>,
+    pub demands : Vec<(String, usize)>,
+    pub weights : Vec<(String, f64)>,
+    pub metadata : BTreeMap<String, Value>
+}
+
+pub fn read_json<R : BufRead>(reader : R) -> Result<JsonInstance, InputError> {
+    let document: JsonDocument = serde_json::from_reader(reader)
+        .map_err(|e| {
+            let location = Location::at(e.line(), e.column());
+            InputError::parse_with(location, "instancia JSON inválida", e)
+        })?;
+
+    let universe: Option<Vec<String>> = document.universe
+        .map(|labels| labels.into_iter().map(JsonLabel::into_string).collect());
+    let mut builder = match &universe {
+        Some(universe) => SetBuilder::with_universe(universe),
+        None => SetBuilder::new(),
+    };
+
+    let mut costs: Vec<(usize, f64)> = Vec::new();
+    let mut names: Vec<(usize, String)> = Vec::new();
+    let mut attributes: Vec<(usize, BTreeMap<String, Value>)> = Vec::new();
+    for (index, subset) in document.subsets.into_iter().enumerate() {
+        let elements: Vec<String> = subset.elements.into_iter().map(JsonLabel::into_string).collect();
+        builder.add_subset(&elements);
+        if let Some(cost) = subset.cost {
+            costs.push((index, cost));
+        }
+        if let Some(name) = subset.name {
+            names.push((index, name));
+        }
+        if !subset.attributes.is_empty() {
+            attributes.push((index, subset.attributes));
+        }
+    }
+
+    Ok(JsonInstance {
+        builder,
+        universe,
+        costs,
+        names,
+        attributes,
+        demands : document.demands.into_iter().collect(),
+        weights : document.weights.into_iter().collect(),
+        metadata : document.metadata
+    })
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CsvOptions {
+    pub delimiter : u8,
+    // Sin comillas los campos se toman tal cual, aunque contengan comillas
+    pub quote : Option<u8>,
+    // Carácter de escape dentro de comillas; sin él una comilla se escapa duplicándola
+    pub escape : Option<u8>,
+    // La primera fila son nombres de columna y se ignora
+    pub header : bool
+}
+
+impl Default for CsvOptions {
+    fn default() -> Self {
+        CsvOptions { delimiter : b',', quote : Some(b'"'), escape : None, header : false }
+    }
+}
+
+impl CsvOptions {
+    fn reader<R : Read>(&self, reader : R) -> (csv::Reader<LineCounter<R>>, LineIndex) {
+        let newlines = Rc::new(RefCell::new(VecDeque::new()));
+        let counter = LineCounter { inner : reader, offset : 0, blank : true, newlines : Rc::clone(&newlines) };
+        let reader = csv::ReaderBuilder::new()
+            .delimiter(self.delimiter)
+            .quoting(self.quote.is_some())
+            .quote(self.quote.unwrap_or(b'"'))
+            .escape(self.escape)
+            .double_quote(self.escape.is_none())
+            .has_headers(self.header)
+            .flexible(true)
+            .from_reader(counter);
+        (reader, LineIndex { newlines, passed : 0 })
+    }
+}
+
+// Subconjuntos agrupados de una lista de aristas. Cada id de subconjunto es su nombre y los
+// subconjuntos quedan en orden de primera aparición.
+#[derive(Debug)]
+pub struct CsvInstance {
+    pub builder : SetBuilder,
+    pub names : Vec<(usize, String)>,
+    pub weights : Vec<(String, f64)>
+}
+
+pub fn read_csv<R : BufRead>(reader : R, options : &CsvOptions) -> Result<CsvInstance, InputError> {
+    let mut index_of: HashMap<String, usize> = HashMap::new();
+    let mut subsets: Vec<Vec<String>> = Vec::new();
+    let mut seen: HashSet<(usize, String)> = HashSet::new();
+    let mut weights: Vec<(String, f64)> = Vec::new();
+    let mut weight_of: HashMap<String, f64> = HashMap::new();
+
+    let (mut reader, mut lines) = options.reader(reader);
+    let mut first = !options.header;
+    for record in reader.records() {
+        let record = record.map_err(|e| csv_error(e, &mut lines, "la lista de aristas"))?;
+        let line = lines.record_line(&record);
+        let fields = record_fields(&record, line)?;
+        if std::mem::take(&mut first) && is_header(&fields, &EDGE_HEADER) {
+            continue;
+        }
+        if fields.len() != 2 && fields.len() != 3 {
+            return Err(InputError::parse(Location::line(line), format!(
+                "se esperaban 2 o 3 campos (subconjunto, elemento[, peso]) y hay {}", fields.len()
+            )));
+        }
+        let (subset, element) = (fields[0], fields[1]);
+        if subset.is_empty() || element.is_empty() {
+            return Err(InputError::parse(Location::line(line), "campo vacío"));
+        }
+
+        if let Some(value) = fields.get(2) {
+            let weight: f64 = value.parse().ok()
+                .filter(|weight: &f64| weight.is_finite() && *weight >= 0.0)
+                .ok_or_else(|| InputError::validation(Location::line(line), format!("peso inválido '{}'", value)))?;
+            match weight_of.get(element) {
+                Some(&previous) if previous != weight => {
+                    return Err(InputError::validation(Location::line(line), format!(
+                        "el elemento {} tiene pesos distintos ({} y {})", element, previous, weight
+                    )));
+                }
+                Some(_) => {}
+                None => {
+                    weight_of.insert(element.to_string(), weight);
+                    weights.push((element.to_string(), weight));
+                }
+            }
+        }
+
+        let next = subsets.len();
+        let index = *index_of.entry(subset.to_string()).or_insert(next);
+        if index == next {
+            subsets.push(Vec::new());
+        }
+        if seen.insert((index, element.to_string())) {
+            subsets[index].push(element.to_string());
+        }
+    }
+
+    let mut names: Vec<(usize, String)> = index_of.into_iter().map(|(name, index)| (index, name)).collect();
+    names.sort_unstable();
+    let mut builder = SetBuilder::new();
+    for subset in &subsets {
+        builder.add_subset(subset);
+    }
+    Ok(CsvInstance { builder, names, weights })
+}
+
+// Tabla subconjunto,costo para una lista de aristas; los subconjuntos se buscan por nombre
+pub fn read_csv_costs<R : BufRead>(reader : R, options : &CsvOptions, names : &[(usize, String)]) -> Result<Vec<(usize, f64)>, InputError> {
+    let index_of: HashMap<&str, usize> = names.iter().map(|(index, name)| (name.as_str(), *index)).collect();
+    let mut costs: Vec<(usize, f64)> = Vec::new();
+
+    let (mut reader, mut lines) = options.reader(reader);
+    let mut first = !options.header;
+    for record in reader.records() {
+        let record = record.map_err(|e| csv_error(e, &mut lines, "la tabla de costos"))?;
+        let line = lines.record_line(&record);
+        let fields = record_fields(&record, line)?;
+        if std::mem::take(&mut first) && is_header(&fields, &COST_HEADER) {
+            continue;
+        }
+        if fields.len() != 2 {
+            return Err(InputError::parse(Location::line(line), format!(
+                "se esperaban 2 campos (subconjunto, costo) y hay {}", fields.len()
+            )));
+        }
+        let index = *index_of.get(fields[0]).ok_or_else(|| InputError::validation(
+            Location::line(line), format!("subconjunto desconocido '{}'", fields[0])
+        ))?;
+        let cost: f64 = fields[1].parse().ok()
+            .filter(|cost: &f64| cost.is_finite() && *cost >= 0.0)
+            .ok_or_else(|| InputError::validation(Location::line(line), format!("costo inválido '{}'", fields[1])))?;
+        costs.push((index, cost));
+    }
+    Ok(costs)
+}
+
+// Encabezados que escribe write_csv; sin -header se reconocen en la primera fila y se saltan
+const EDGE_HEADER: [&str; 3] = ["subset", "element", "weight"];
+const COST_HEADER: [&str; 2] = ["subset", "cost"];
+
+fn is_header(fields : &[&str], names : &[&str]) -> bool {
+    fields.len() >= 2 && fields.len() <= names.len()
+        && fields.iter().zip(names).all(|(field, name)| field.eq_ignore_ascii_case(name))
+}
+
+// Campos sin espacios en los extremos, como en txt. Un salto de línea dentro de un campo casi
+// siempre es una comilla sin cerrar, así que se reporta en lugar de aceptarlo.
+fn record_fields(record : &csv::StringRecord, line : usize) -> Result<Vec<&str>, InputError> {
+    if record.iter().any(|field| field.contains(['\n', '\r'])) {
+        return Err(InputError::parse(Location::line(line), "campo con salto de línea (¿comilla sin cerrar?)"));
+    }
+    Ok(record.iter().map(str::trim).collect())
+}
+
+fn csv_error(error : csv::Error, lines : &mut LineIndex, what : &str) -> InputError {
+    match (error.kind(), error.position()) {
+        (csv::ErrorKind::Utf8 { err, .. }, Some(position)) => {
+            let location = Location::at(lines.line_at(position.byte()), err.field() + 1);
+            InputError::parse_with(location, format!("texto que no es UTF-8 en {}", what), error)
+        }
+        _ => InputError::parse_with(Location::default(), format!("error al leer {}", what), error),
+    }
+}
+
+// Guarda la posición de cada salto de línea que lee el csv y si termina una línea vacía. El
+// contador de líneas de csv no incluye las líneas vacías que se salta, así que la línea de una
+// fila se calcula a partir de su posición en bytes.
+struct LineCounter<R> {
+    inner : R,
+    offset : u64,
+    blank : bool,
+    newlines : Rc<RefCell<VecDeque<(u64, bool)>>>
+}
+
+impl<R : Read> Read for LineCounter<R> {
+    fn read(&mut self, buf : &mut [u8]) -> std::io::Result<usize> {
+        let read = self.inner.read(buf)?;
+        let mut newlines = self.newlines.borrow_mut();
+        for (index, &byte) in buf[..read].iter().enumerate() {
+            match byte {
+                b'\n' => {
+                    newlines.push_back((self.offset + index as u64, self.blank));
+                    self.blank = true;
+                }
+                b'\r' => {}
+                _ => self.blank = false,
+            }
+        }
+        self.offset += read as u64;
+        Ok(read)
+    }
+}
+
+// Las filas llegan en orden, así que se descartan los saltos de línea ya pasados
+struct LineIndex {
+    newlines : Rc<RefCell<VecDeque<(u64, bool)>>>,
+    passed : usize
+}
+
+impl LineIndex {
+    // La posición de una fila precedida por líneas vacías es la de la primera de ellas, y con
+    // fin de línea \r\n puede ser la del \n que cierra la línea anterior
+    fn line_at(&mut self, byte : u64) -> usize {
+        let mut newlines = self.newlines.borrow_mut();
+        while newlines.front().is_some_and(|&(newline, blank)| newline <= byte || blank) {
+            newlines.pop_front();
+            self.passed += 1;
+        }
+        self.passed + 1
+    }
+
+    fn record_line(&mut self, record : &csv::StringRecord) -> usize {
+        record.position().map_or(0, |position| self.line_at(position.byte()))
+    }
+}
+
+pub fn write_instance<W : Write>(set : &Set, format : InstanceFormat, writer : W) -> Result<Vec<ConversionWarning>, InputError> {
+    let result = match format {
+        InstanceFormat::Txt => {
+            let labels = element_labels(set);
+            // '=' solo separa pares en #demand y #weight, así que solo se prohíbe en las etiquetas
+            // que se escriben en esos encabezados
+            let in_header_pair = |element : usize| set.get_demand(element) != 1 || set.get_element_weight(element) != 1.0;
+            if let Some(label) = labels.iter()
+                .enumerate()
+                .find(|&(element, label)| !is_txt_label(label) || (in_header_pair(element) && label.contains('=')))
+                .map(|(_, label)| label) {
+                return Err(InputError::validation(
+                    Location::default(), format!("la etiqueta '{}' no se puede escribir en formato txt", label)
+                ));
+            }
+            write_txt(set, &labels, writer)
+        }
+        InstanceFormat::OrLibrary => write_orlib(set, writer),
+        InstanceFormat::Json => write_json(set, writer),
+        InstanceFormat::Csv => {
+            let labels = element_labels(set);
+            let names: Vec<String> = (0..set.get_size_subsets()).map(|subset| set.get_subset_name(subset).into_owned()).collect();
+            if let Some(label) = labels.iter().chain(names.iter()).find(|label| !is_csv_field(label)) {
+                return Err(InputError::validation(
+                    Location::default(), format!("la etiqueta '{}' no se puede escribir en formato csv", label)
+                ));
+            }
+            write_csv(set, &labels, &names, writer)
+        }
+    };
+    result.map_err(|source| InputError::Io { location : Location::default(), source })
+}
+
+pub fn write_instance_file(set : &Set, format : InstanceFormat, path : &str) -> Result<Vec<ConversionWarning>, InputError> {
+    let file = File::create(path).map_err(|e| InputError::io(path, e))?;
+    write_instance(set, format, BufWriter::new(file)).map_err(|e| e.in_file(path))
+}
+
+fn element_labels(set : &Set) -> Vec<String> {
+    (0..set.get_size_set())
+        .map(|element| set.get_element_name(element).unwrap_or_default().into_owned())
+        .collect()
+}
+
+// El formato txt guarda el universo en el orden del Set y los encabezados que no son los valores
+// por defecto; no tiene dónde guardar nombres ni atributos de subconjuntos ni metadatos
+fn write_txt<W : Write>(set : &Set, labels : &[String], mut writer : W) -> std::io::Result<Vec<ConversionWarning>> {
+    let warnings: Vec<ConversionWarning> = lost_subset_names(set, InstanceFormat::Txt).into_iter()
+        .chain(lost_subset_attributes(set, InstanceFormat::Txt))
+        .chain(lost_metadata(set, InstanceFormat::Txt))
+        .collect();
+    writeln!(writer, "#universe: {}", labels.join(","))?;
+    let demands: Vec<String> = (0..set.get_size_set())
+        .filter(|&element| set.get_demand(element) != 1)
+        .map(|element| format!("{}={}", labels[element], set.get_demand(element)))
+        .collect();
+    if !demands.is_empty() {
+        writeln!(writer, "#demand: {}", demands.join(","))?;
+    }
+    let costs: Vec<String> = (0..set.get_size_subsets())
+        .filter(|&subset| set.get_subset_cost(subset) != 1.0)
+        .map(|subset| format!("{}={}", subset, set.get_subset_cost(subset)))
+        .collect();
+    if !costs.is_empty() {
+        writeln!(writer, "#cost: {}", costs.join(","))?;
+    }
+    let weights: Vec<String> = (0..set.get_size_set())
+        .filter(|&element| set.get_element_weight(element) != 1.0)
+        .map(|element| format!("{}={}", labels[element], set.get_element_weight(element)))
+        .collect();
+    if !weights.is_empty() {
+        writeln!(writer, "#weight: {}", weights.join(","))?;
+    }
+
+    for subset in 0..set.get_size_subsets() {
+        let names: Vec<&str> = set.elements_of(subset).iter().map(|&element| labels[element].as_str()).collect();
+        writeln!(writer, "{}", names.join(","))?;
+    }
+    writer.flush()?;
+    Ok(warnings)
+}
+
+fn write_orlib<W : Write>(set : &Set, mut writer : W) -> std::io::Result<Vec<ConversionWarning>> {
+    let format = InstanceFormat::OrLibrary;
+    let mut warnings: Vec<ConversionWarning> = lost_subset_names(set, format).into_iter()
+        .chain(lost_subset_attributes(set, format))
+        .chain(lost_metadata(set, format))
+        .collect();
+
+    let renumbered = (0..set.get_size_set())
+        .any(|element| set.get_element_name(element).is_none_or(|name| name != (element + 1).to_string()));
+    if renumbered {
+        warnings.push(ConversionWarning::ElementLabels { format });
+    }
+    let weights = (0..set.get_size_set()).filter(|&element| set.get_element_weight(element) != 1.0).count();
+    if weights > 0 {
+        warnings.push(ConversionWarning::ElementWeights { format, count : weights });
+    }
+    let demands = (0..set.get_size_set()).filter(|&element| set.get_demand(element) != 1).count();
+    if demands > 0 {
+        warnings.push(ConversionWarning::Demands { format, count : demands });
+    }
+    let fractional = (0..set.get_size_subsets()).filter(|&subset| set.get_subset_cost(subset).fract() != 0.0).count();
+    if fractional > 0 {
+        warnings.push(ConversionWarning::NonIntegerCosts { format, count : fractional });
+    }
+
+    writeln!(writer, "{} {}", set.get_size_set(), set.get_size_subsets())?;
+    let costs: Vec<String> = (0..set.get_size_subsets()).map(|subset| set.get_subset_cost(subset).to_string()).collect();
+    write_wrapped(&mut writer, &costs)?;
+    for element in 0..set.get_size_set() {
+        let covering = set.subsets_covering(element);
+        writeln!(writer, "{}", covering.len())?;
+        let columns: Vec<String> = covering.iter().map(|subset| (subset + 1).to_string()).collect();
+        write_wrapped(&mut writer, &columns)?;
+    }
+    writer.flush()?;
+    Ok(warnings)
+}
+
+// El formato JSON guarda todo lo que tiene el Set
+fn write_json<W : Write>(set : &Set, mut writer : W) -> std::io::Result<Vec<ConversionWarning>> {
+    let labels = element_labels(set);
+    let subsets: Vec<JsonSubset> = (0..set.get_size_subsets())
+        .map(|subset| JsonSubset {
+            name : set.subset_names[subset].clone(),
+            elements : set.elements_of(subset).iter().map(|&element| JsonLabel::Text(labels[element].clone())).collect(),
+            cost : Some(set.get_subset_cost(subset)).filter(|&cost| cost != 1.0),
+            attributes : set.subset_attributes[subset].clone()
+        })
+        .collect();
+    let document = JsonDocument {
+        metadata : set.metadata.clone(),
+        universe : Some(labels.iter().cloned().map(JsonLabel::Text).collect()),
+        subsets,
+        demands : (0..set.get_size_set())
+            .filter(|&element| set.get_demand(element) != 1)
+            .map(|element| (labels[element].clone(), set.get_demand(element)))
+            .collect(),
+        weights : (0..set.get_size_set())
+            .filter(|&element| set.get_element_weight(element) != 1.0)
+            .map(|element| (labels[element].clone(), set.get_element_weight(element)))
+            .collect()
+    };
+
+    serde_json::to_writer_pretty(&mut writer, &document)?;
+    writeln!(writer)?;
+    writer.flush()?;
+    Ok(Vec::new())
+}
+
+// Lista de aristas con los nombres de los subconjuntos (S<índice> si no tienen) y una columna de
+// pesos solo si algún elemento no pesa 1
+fn write_csv<W : Write>(set : &Set, labels : &[String], names : &[String], writer : W) -> std::io::Result<Vec<ConversionWarning>> {
+    let format = InstanceFormat::Csv;
+    let mut warnings: Vec<ConversionWarning> = lost_subset_attributes(set, format).into_iter()
+        .chain(lost_metadata(set, format))
+        .collect();
+    let costs = (0..set.get_size_subsets()).filter(|&subset| set.get_subset_cost(subset) != 1.0).count();
+    if costs > 0 {
+        warnings.push(ConversionWarning::SubsetCosts { format, count : costs });
+    }
+    let demands = (0..set.get_size_set()).filter(|&element| set.get_demand(element) != 1).count();
+    if demands > 0 {
+        warnings.push(ConversionWarning::Demands { format, count : demands });
+    }
+    let alone = (0..set.get_size_set()).filter(|&element| set.subsets_covering(element).is_empty()).count();
+    if alone > 0 {
+        warnings.push(ConversionWarning::ElementsWithoutSubsets { format, count : alone });
+    }
+
+    let weighted = (0..set.get_size_set()).any(|element| set.get_element_weight(element) != 1.0);
+    let mut csv_writer = csv::Writer::from_writer(writer);
+    let columns = if weighted { 3 } else { 2 };
+    csv_writer.write_record(&EDGE_HEADER[..columns])?;
+    for (subset, name) in names.iter().enumerate() {
+        for &element in set.elements_of(subset) {
+            if weighted {
+                csv_writer.write_record([name, &labels[element], &set.get_element_weight(element).to_string()])?;
+            } else {
+                csv_writer.write_record([name, &labels[element]])?;
+            }
+        }
+    }
+    csv_writer.flush()?;
+    Ok(warnings)
+}
+
+// Solo los formatos JSON y csv guardan nombres de subconjuntos
+fn lost_subset_names(set : &Set, format : InstanceFormat) -> Option<ConversionWarning> {
+    let count = set.subset_names.iter().filter(|name| name.is_some()).count();
+    (count > 0).then_some(ConversionWarning::SubsetNames { format, count })
+}
+
+// Solo el formato JSON guarda atributos de subconjuntos
+fn lost_subset_attributes(set : &Set, format : InstanceFormat) -> Option<ConversionWarning> {
+    let count = set.subset_attributes.iter().filter(|attributes| !attributes.is_empty()).count();
+    (count > 0).then_some(ConversionWarning::SubsetAttributes { format, count })
+}
+
+// Solo el formato JSON guarda metadatos
+fn lost_metadata(set : &Set, format : InstanceFormat) -> Option<ConversionWarning> {
+    (!set.metadata.is_empty()).then_some(ConversionWarning::Metadata { format, count : set.metadata.len() })
+}
+
+fn write_wrapped<W : Write>(writer : &mut W, numbers : &[String]) -> std::io::Result<()> {
+    for line in numbers.chunks(ORLIB_NUMBERS_PER_LINE) {
+        writeln!(writer, " {}", line.join(" "))?;
+    }
+    Ok(())
+}
+
+// Una etiqueta se puede escribir en txt si al volver a leerla queda igual
+fn is_txt_label(label : &str) -> bool {
+    !label.is_empty()
+        && label.trim() == label
+        && !label.starts_with('#')
+        && !label.contains([',', '\n', '\r'])
+}
+
+// Un campo csv se lee igual si no está vacío, no tiene espacios en los extremos (se recortan al
+// leer) ni saltos de línea
+fn is_csv_field(label : &str) -> bool {
+    !label.is_empty() && label.trim() == label && !label.contains(['\n', '\r'])
+}
+
+// Números separados por espacios en cualquier cantidad de líneas, recordando la línea actual
+struct Tokens<R : BufRead> {
+    reader : R,
+    pending : Vec<String>,
+    line : usize
+}
+
+impl<R : BufRead> Tokens<R> {
+    fn new(reader : R) -> Self {
+        Tokens { reader, pending : Vec::new(), line : 0 }
+    }
+
+    fn next_token(&mut self) -> Result<Option<String>, InputError> {
+        while self.pending.is_empty() {
+            let mut line = String::new();
+            let read = self.reader.read_line(&mut line)
+                .map_err(|source| InputError::Io { location : Location::line(self.line + 1), source })?;
+            if read == 0 {
+                return Ok(None);
+            }
+            self.line += 1;
+            self.pending = line.split_whitespace().rev().map(|token| token.to_string()).collect();
+        }
+        Ok(self.pending.pop())
+    }
+
+    fn next_number<T>(&mut self, what : &str) -> Result<T, InputError>
+    where T : std::str::FromStr, T::Err : std::error::Error + Send + Sync + 'static {
+        let token = self.next_token()?
+            .ok_or_else(|| InputError::parse(Location::line(self.line), format!("fin de archivo inesperado: falta {}", what)))?;
+        token.parse().map_err(|e| InputError::parse_with(
+            Location::line(self.line), format!("se esperaba {} y se encontró '{}'", what, token), e
+        ))
+    }
+}
@@ -1 +1,3 @@
-pub mod read_input;
\ No newline at end of file
+pub mod read_input;
+pub mod natural_order;
+pub mod instance_format;
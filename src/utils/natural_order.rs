@@ -0,0 +1,42 @@
+use std::cmp::Ordering;
+
+// Orden natural: las secuencias de dígitos se comparan por su valor numérico, de modo que
+// "2" < "10" y "s2" < "s10". Los empates (por ejemplo "01" y "1") se rompen con el orden
+// lexicográfico para que sea un orden total.
+pub fn natural_cmp(a : &str, b : &str) -> Ordering {
+    let mut left = a.as_bytes();
+    let mut right = b.as_bytes();
+
+    while let (Some(&x), Some(&y)) = (left.first(), right.first()) {
+        if x.is_ascii_digit() && y.is_ascii_digit() {
+            let (number_a, rest_a) = split_digits(left);
+            let (number_b, rest_b) = split_digits(right);
+            let ordering = compare_numbers(number_a, number_b);
+            if ordering != Ordering::Equal {
+                return ordering;
+            }
+            left = rest_a;
+            right = rest_b;
+        } else {
+            if x != y {
+                return x.cmp(&y);
+            }
+            left = &left[1..];
+            right = &right[1..];
+        }
+    }
+
+    left.len().cmp(&right.len()).then_with(|| a.cmp(b))
+}
+
+fn split_digits(bytes : &[u8]) -> (&[u8], &[u8]) {
+    let end = bytes.iter().position(|byte| !byte.is_ascii_digit()).unwrap_or(bytes.len());
+    bytes.split_at(end)
+}
+
+fn compare_numbers(a : &[u8], b : &[u8]) -> Ordering {
+    let trim = |digits : &[u8]| -> usize { digits.iter().take_while(|&&digit| digit == b'0').count() };
+    let a = &a[trim(a)..];
+    let b = &b[trim(b)..];
+    a.len().cmp(&b.len()).then_with(|| a.cmp(b))
+}
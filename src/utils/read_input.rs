@@ -1,36 +1,238 @@
-use std::collections::HashSet;
+use std::collections::hash_map::Entry;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::error::Error;
 use std::fmt;
-use std::fs;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
 
+use flate2::read::MultiGzDecoder;
+use serde_json::Value;
+
+use crate::entity::cover_mode::{ModeRequest, PartialTarget};
+use crate::entity::labels::Labels;
+use crate::entity::max_coverage::Budget;
+use crate::entity::objective::{objective_from_name, Objective, OBJECTIVE_NAMES};
+use crate::entity::set::{ElementOrder, SetError};
+use crate::entity::set_builder::SetBuilder;
+use crate::entity::stats::StatsFormat;
+use crate::solver::registry::SolverRegistry;
+use crate::solver::Solver;
+use super::instance_format::{read_csv, read_csv_costs, read_json, read_orlib, CsvOptions, InstanceFormat};
+use crate::solver::local_search::Strategy;
+use crate::solver::termination::Termination;
+
+const READ_BUFFER_SIZE: usize = 1 << 20;
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+const PROGRESS_INTERVAL: usize = 100_000;
+const DEFAULT_SOLVER: &str = "rfd";
+const DEFAULT_CHECKPOINT_INTERVAL: usize = 50;
+
+// Lugar de un error dentro de un archivo; la línea y la columna empiezan en 1
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Location {
+    pub path : Option<PathBuf>,
+    pub line : Option<usize>,
+    pub column : Option<usize>
+}
+
+impl Location {
+    pub fn line(line : usize) -> Self {
+        Location { path : None, line : Some(line), column : None }
+    }
+
+    pub fn at(line : usize, column : usize) -> Self {
+        Location { path : None, line : Some(line), column : Some(column) }
+    }
+
+    pub fn file<P : Into<PathBuf>>(path : P) -> Self {
+        Location { path : Some(path.into()), line : None, column : None }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.path.is_none() && self.line.is_none() && self.column.is_none()
+    }
+}
+
+impl fmt::Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut parts: Vec<String> = Vec::new();
+        if let Some(path) = &self.path {
+            parts.push(path.display().to_string());
+        }
+        if let Some(line) = self.line {
+            parts.push(format!("línea {}", line));
+        }
+        if let Some(column) = self.column {
+            parts.push(format!("columna {}", column));
+        }
+        write!(f, "{}", parts.join(", "))
+    }
+}
+
+// Errores de la línea de comandos (argumentos y semillas), de entrada/salida (rutas y archivos)
+// y del contenido de los archivos: sintaxis (Parse, EmptyElement, InvalidHeader) y valores bien
+// escritos pero inválidos (Validation, InvalidSet)
 #[derive(Debug)]
 pub enum InputError {
-    FileNotFound(String),
-    InvalidFormat(String),
-    InvalidPath(String),
     NoArgs,
     InvalidArgumentSeed,
     InvalidSeed,
+    InvalidArgument { flag : String, reason : String },
+    // Falta la ruta o no tiene la extensión que espera el formato
+    InvalidPath { path : String, reason : String },
+    FileNotFound { path : PathBuf, source : io::Error },
+    Io { location : Location, source : io::Error },
+    EmptyFile { location : Location },
+    EmptyElement { location : Location },
+    InvalidHeader { location : Location, reason : String },
+    Parse { location : Location, reason : String, source : Option<Box<dyn Error + Send + Sync>> },
+    Validation { location : Location, reason : String },
+    InvalidSet { location : Location, source : SetError },
+}
+
+impl InputError {
+    pub fn missing_value(flag : &str) -> Self {
+        InputError::InvalidArgument { flag : flag.to_string(), reason : "falta el valor".to_string() }
+    }
+
+    pub fn invalid_argument<S : Into<String>>(flag : &str, reason : S) -> Self {
+        InputError::InvalidArgument { flag : flag.to_string(), reason : reason.into() }
+    }
+
+    pub fn parse<S : Into<String>>(location : Location, reason : S) -> Self {
+        InputError::Parse { location, reason : reason.into(), source : None }
+    }
+
+    pub fn parse_with<S : Into<String>, E : Error + Send + Sync + 'static>(location : Location, reason : S, source : E) -> Self {
+        InputError::Parse { location, reason : reason.into(), source : Some(Box::new(source)) }
+    }
+
+    pub fn validation<S : Into<String>>(location : Location, reason : S) -> Self {
+        InputError::Validation { location, reason : reason.into() }
+    }
+
+    // Error de E/S: si el archivo no existe se reporta como FileNotFound
+    pub fn io<P : Into<PathBuf>>(path : P, source : io::Error) -> Self {
+        if source.kind() == io::ErrorKind::NotFound {
+            InputError::FileNotFound { path : path.into(), source }
+        } else {
+            InputError::Io { location : Location::file(path), source }
+        }
+    }
+
+    pub fn location(&self) -> Option<&Location> {
+        match self {
+            InputError::Io { location, .. }
+            | InputError::EmptyFile { location }
+            | InputError::EmptyElement { location }
+            | InputError::InvalidHeader { location, .. }
+            | InputError::Parse { location, .. }
+            | InputError::Validation { location, .. }
+            | InputError::InvalidSet { location, .. } => Some(location),
+            _ => None,
+        }
+    }
+
+    // Los lectores no conocen la ruta del archivo; quien lo abrió la agrega al error
+    pub fn in_file<P : AsRef<Path>>(mut self, path : P) -> Self {
+        match &mut self {
+            InputError::Io { location, .. }
+            | InputError::EmptyFile { location }
+            | InputError::EmptyElement { location }
+            | InputError::InvalidHeader { location, .. }
+            | InputError::Parse { location, .. }
+            | InputError::Validation { location, .. }
+            | InputError::InvalidSet { location, .. } => {
+                location.path.get_or_insert_with(|| path.as_ref().to_path_buf());
+            }
+            _ => {}
+        }
+        self
+    }
 }
 
 impl fmt::Display for InputError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(location) = self.location().filter(|location| !location.is_empty()) {
+            write!(f, "{}: ", location)?;
+        }
         match self {
-            InputError::FileNotFound(msg) => write!(f, "File not found: {}", msg),
-            InputError::InvalidFormat(msg) => write!(f, "Invalid format: {}", msg),
-            InputError::InvalidPath(msg) => write!(f,"Invalid path: {}",msg),
-            InputError::NoArgs => write!(f,"Faltan argumentos"),
-            InputError::InvalidArgumentSeed => write!(f,"No se pueden usar ambos argumentos"),
-            InputError::InvalidSeed => write!(f,"Seed inválida")
+            InputError::NoArgs => write!(f, "Faltan argumentos"),
+            InputError::InvalidArgumentSeed => write!(f, "No se pueden usar ambos argumentos"),
+            InputError::InvalidSeed => write!(f, "Seed inválida"),
+            InputError::InvalidArgument { flag, reason } => write!(f, "Argumento {} inválido: {}", flag, reason),
+            InputError::InvalidPath { path, reason } => write!(f, "Ruta inválida '{}': {}", path, reason),
+            InputError::FileNotFound { path, source } => write!(f, "No se encontró el archivo {}: {}", path.display(), source),
+            InputError::Io { source, .. } => write!(f, "Error de lectura o escritura: {}", source),
+            InputError::EmptyFile { .. } => write!(f, "El archivo no contiene subconjuntos"),
+            InputError::EmptyElement { .. } => write!(f, "Elemento vacío"),
+            InputError::InvalidHeader { reason, .. } => write!(f, "Encabezado inválido: {}", reason),
+            InputError::Parse { reason, source : Some(source), .. } => write!(f, "{}: {}", reason, source),
+            InputError::Parse { reason, source : None, .. } | InputError::Validation { reason, .. } => write!(f, "{}", reason),
+            InputError::InvalidSet { source, .. } => write!(f, "Instancia inválida: {}", source),
         }
     }
 }
 
-impl std::error::Error for InputError {}
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            InputError::FileNotFound { source, .. } | InputError::Io { source, .. } => Some(source),
+            InputError::Parse { source, .. } => source.as_deref().map(|source| source as &(dyn Error + 'static)),
+            InputError::InvalidSet { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum InputWarning {
+    BlankLine { line : usize },
+    DuplicateSubset { line : usize, first_line : usize },
+}
+
+impl fmt::Display for InputWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputWarning::BlankLine { line } => write!(f, "Línea {} vacía, se ignora", line),
+            InputWarning::DuplicateSubset { line, first_line } => {
+                write!(f, "El subconjunto de la línea {} duplica al de la línea {}", line, first_line)
+            }
+        }
+    }
+}
+
+// Encabezados y metadatos de una lectura, que se guardan en ReadInput solo si terminó sin errores
+struct ParsedInstance {
+    subset_lines : Vec<usize>,
+    universe : Option<(Vec<String>, usize)>,
+    demands : Option<(Vec<(String, usize)>, usize)>,
+    costs : Option<(Vec<(usize, f64)>, usize)>,
+    weights : Option<(Vec<(String, f64)>, usize)>,
+    warnings : Vec<InputWarning>
+}
 
 pub struct ReadInput {
     pub args : Vec<String>,
     pub seeds : Vec<i32>,
-    pub subset : Vec<Vec<String>>
+    pub subset : Vec<Vec<String>>,
+    pub subset_lines : Vec<usize>,
+    pub universe : Option<Vec<String>>,
+    pub universe_line : Option<usize>,
+    pub demands : Vec<(String, usize)>,
+    pub demand_line : Option<usize>,
+    pub subset_costs : Vec<(usize, f64)>,
+    pub cost_line : Option<usize>,
+    pub element_weights : Vec<(String, f64)>,
+    pub weight_line : Option<usize>,
+    pub subset_names : Vec<(usize, String)>,
+    pub subset_attributes : Vec<(usize, BTreeMap<String, Value>)>,
+    pub metadata : BTreeMap<String, Value>,
+    pub warnings : Vec<InputWarning>
 }
 
 impl ReadInput {
@@ -38,50 +240,308 @@ impl ReadInput {
         if args.len() == 1 {
             return Err(InputError::NoArgs);
         }
-        Ok(ReadInput { args, seeds: vec![], subset : vec![] })
+        Ok(ReadInput { args, seeds: vec![], subset : vec![], subset_lines : vec![], universe : None, universe_line : None,
+            demands : vec![], demand_line : None, subset_costs : vec![], cost_line : None,
+            element_weights : vec![], weight_line : None, subset_names : vec![], subset_attributes : vec![],
+            metadata : BTreeMap::new(), warnings : vec![] })
     }
 
     pub fn read_file_subset(&mut self) -> Result<Vec<Vec<String>>, InputError> {
         if !self.subset.is_empty() {
             return Ok(self.subset.clone());
         }
+        // Las líneas como cadenas solo existen en txt; los demás formatos se leen con load_set
+        let format = self.get_input_format()?;
+        if format != InstanceFormat::Txt {
+            return Err(InputError::invalid_argument("-from", format!("{} solo se puede leer como conjunto, no por líneas", format)));
+        }
+
+        let reader = self.open_instance()?;
+        let mut subsets: Vec<Vec<String>> = Vec::new();
+        let parsed = self.parse_instance(reader, |_, subset| {
+            let mut seen: HashSet<&str> = HashSet::new();
+            subsets.push(subset.iter().filter(|element| seen.insert(element)).map(|element| element.to_string()).collect());
+        }).map_err(|e| self.locate(e))?;
 
-        let position = if let Some(pos) = self.get_position_flag("-c") {
-            if pos + 1 >= self.args.len() {
-                return Err(InputError::FileNotFound("No se encontro el valor de path".to_string()));
+        self.store_instance(parsed);
+        self.subset = subsets;
+        Ok(self.subset.clone())
+    }
+
+    // Lee la instancia en una sola pasada construyendo el conjunto sin guardar las líneas ni los
+    // subconjuntos como cadenas. Los errores de la instancia se reportan al llamar build.
+    pub fn load_set(&mut self) -> Result<SetBuilder, InputError> {
+        self.read_set().map_err(|e| self.locate(e))
+    }
+
+    fn read_set(&mut self) -> Result<SetBuilder, InputError> {
+        match self.get_input_format()? {
+            InstanceFormat::OrLibrary => {
+                let instance = read_orlib(self.open_instance()?)?;
+                self.subset_costs = instance.costs;
+                return Ok(instance.builder);
+            }
+            InstanceFormat::Json => {
+                let instance = read_json(self.open_instance()?)?;
+                self.universe = instance.universe;
+                self.subset_costs = instance.costs;
+                self.subset_names = instance.names;
+                self.subset_attributes = instance.attributes;
+                self.demands = instance.demands;
+                self.element_weights = instance.weights;
+                self.metadata = instance.metadata;
+                return Ok(instance.builder);
             }
-            pos
+            InstanceFormat::Csv => {
+                let options = self.get_csv_options()?;
+                let instance = read_csv(self.open_instance()?, &options)?;
+                if let Some(path) = self.get_cost_table()? {
+                    let file = File::open(&path).map_err(|e| InputError::io(&path, e))?;
+                    self.subset_costs = read_csv_costs(BufReader::new(file), &options, &instance.names)
+                        .map_err(|e| e.in_file(&path))?;
+                }
+                self.subset_names = instance.names;
+                self.element_weights = instance.weights;
+                return Ok(instance.builder);
+            }
+            InstanceFormat::Txt => {}
+        }
+
+        let reader = self.open_instance()?;
+        let mut builder: Option<SetBuilder> = None;
+        let parsed = self.parse_instance(reader, |universe, subset| {
+            builder.get_or_insert_with(|| match universe {
+                Some(universe) => SetBuilder::with_universe(universe),
+                None => SetBuilder::new(),
+            }).add_subset(subset);
+        })?;
+
+        self.store_instance(parsed);
+        Ok(builder.unwrap_or_default())
+    }
+
+    // Abre el archivo de -c con un lector con buffer; si empieza con la firma de gzip se
+    // descomprime al vuelo
+    fn open_instance(&self) -> Result<Box<dyn BufRead>, InputError> {
+        let path = self.instance_path()?;
+        let extension = self.get_input_format()?.extension();
+        if !path.ends_with(&format!(".{}", extension)) && !path.ends_with(&format!(".{}.gz", extension)) {
+            return Err(InputError::InvalidPath {
+                path : path.to_string(),
+                reason : format!("debe ser un archivo .{} o .{}.gz", extension, extension)
+            });
+        }
+
+        let file = File::open(path).map_err(|e| InputError::io(path, e))?;
+        let mut reader = BufReader::with_capacity(READ_BUFFER_SIZE, file);
+        let is_gzip = reader.fill_buf()
+            .map_err(|e| InputError::io(path, e))?
+            .starts_with(&GZIP_MAGIC);
+
+        if is_gzip {
+            Ok(Box::new(BufReader::with_capacity(READ_BUFFER_SIZE, MultiGzDecoder::new(reader))))
         } else {
-            return Err(InputError::InvalidPath("No se encontro la bandera".to_string()));
-        };
+            Ok(Box::new(reader))
+        }
+    }
+
+    pub fn instance_path(&self) -> Result<&str, InputError> {
+        let position = self.get_position_flag("-c").ok_or_else(|| InputError::InvalidPath {
+            path : String::new(),
+            reason : "falta la bandera -c".to_string()
+        })?;
+        self.args.get(position + 1).map(String::as_str).ok_or_else(|| InputError::InvalidPath {
+            path : String::new(),
+            reason : "falta el valor de -c".to_string()
+        })
+    }
+
+    // Agrega la ruta de la instancia a los errores de lectura que solo conocen la línea
+    fn locate(&self, error : InputError) -> InputError {
+        match self.instance_path() {
+            Ok(path) => error.in_file(path),
+            Err(_) => error,
+        }
+    }
 
-        let next_arg = &self.args[position + 1];
-        if next_arg.ends_with(".txt") {
-            let content = fs::read_to_string(next_arg)
-                        .map_err(|_| InputError::InvalidPath("Error al leer el archivo".to_string()))?;
+    // Recorre la instancia línea por línea: procesa los encabezados y entrega cada subconjunto
+    // a on_subset junto con el universo declarado, sin acumular el contenido del archivo
+    fn parse_instance<R : BufRead>(&self, mut reader : R, mut on_subset : impl FnMut(Option<&[String]>, &[&str]))
+        -> Result<ParsedInstance, InputError> {
+        let verbose = self.get_verbose();
+        let mut subset_lines: Vec<usize> = Vec::new();
+        let mut universe: Option<(Vec<String>, usize)> = None;
+        let mut demands: Option<(Vec<(String, usize)>, usize)> = None;
+        let mut costs: Option<(Vec<(usize, f64)>, usize)> = None;
+        let mut weights: Option<(Vec<(String, f64)>, usize)> = None;
+        let mut warnings: Vec<InputWarning> = Vec::new();
+        // Cada subconjunto se identifica por sus elementos internados, ordenados y sin repetir, para
+        // detectar duplicados sin guardar las cadenas de cada línea
+        let mut element_ids = Labels::numeric(std::iter::empty());
+        let mut first_line_of: HashMap<Vec<usize>, usize> = HashMap::new();
+        let mut buffer = String::new();
+        let mut bytes_read: usize = 0;
+        let mut line_number: usize = 0;
 
-            let lines: Vec<&str> = content.lines().collect();
-            for line in lines {
-                let parts_iter: Vec<&str> = line.split(",").collect::<Vec<&str>>();
-                let mut unique_parts_ordered: Vec<String> = Vec::new();
-                let mut seen: HashSet<&str> = HashSet::new();
+        loop {
+            buffer.clear();
+            let read = reader.read_line(&mut buffer)
+                .map_err(|source| InputError::Io { location : Location::line(line_number + 1), source })?;
+            if read == 0 {
+                break;
+            }
+            bytes_read += read;
+            line_number += 1;
+            let line = buffer.trim_end_matches(['\n', '\r']);
 
-                for part in parts_iter {
-                    if seen.insert(part) {
-                        unique_parts_ordered.push(self.get_element(part).unwrap());
+            if line.trim().is_empty() {
+                warnings.push(InputWarning::BlankLine { line: line_number });
+                continue;
+            }
+
+            if let Some(header) = line.trim_start().strip_prefix('#') {
+                if !subset_lines.is_empty() {
+                    return Err(InputError::InvalidHeader {
+                        location: Location::line(line_number),
+                        reason: "los encabezados deben aparecer antes de los subconjuntos".to_string()
+                    });
+                }
+
+                let offset = line.len() - header.len();
+                let (name, values) = header.split_once(':').ok_or_else(|| InputError::InvalidHeader {
+                    location: Location::line(line_number),
+                    reason: "se esperaba '#<nombre>: <valores>'".to_string()
+                })?;
+
+                match name.trim() {
+                    "universe" => {
+                        if universe.is_some() {
+                            return Err(InputError::InvalidHeader {
+                                location: Location::line(line_number),
+                                reason: "el universo ya fue declarado".to_string()
+                            });
+                        }
+                        let mut seen: HashSet<&str> = HashSet::new();
+                        let elements: Vec<String> = self.parse_elements(values, line_number, offset + name.len() + 2)?
+                            .into_iter()
+                            .filter(|element| seen.insert(element))
+                            .map(str::to_string)
+                            .collect();
+                        universe = Some((elements, line_number));
+                    }
+                    "demand" => {
+                        if demands.is_some() {
+                            return Err(InputError::InvalidHeader {
+                                location: Location::line(line_number),
+                                reason: "la demanda ya fue declarada".to_string()
+                            });
+                        }
+                        let mut parsed: Vec<(String, usize)> = Vec::new();
+                        for (element, value) in self.parse_pairs(values, line_number, offset + name.len() + 2)? {
+                            let demand: usize = value.parse().map_err(|_| InputError::InvalidHeader {
+                                location: Location::line(line_number),
+                                reason: format!("demanda inválida '{}' para el elemento {}", value, element)
+                            })?;
+                            parsed.push((element, demand));
+                        }
+                        demands = Some((parsed, line_number));
+                    }
+                    "cost" => {
+                        if costs.is_some() {
+                            return Err(InputError::InvalidHeader {
+                                location: Location::line(line_number),
+                                reason: "los costos ya fueron declarados".to_string()
+                            });
+                        }
+                        let mut parsed: Vec<(usize, f64)> = Vec::new();
+                        for (subset, value) in self.parse_pairs(values, line_number, offset + name.len() + 2)? {
+                            let index: usize = subset.parse().map_err(|_| InputError::InvalidHeader {
+                                location: Location::line(line_number),
+                                reason: format!("índice de subconjunto inválido '{}'", subset)
+                            })?;
+                            let cost: f64 = value.parse().ok()
+                                .filter(|cost: &f64| cost.is_finite() && *cost >= 0.0)
+                                .ok_or_else(|| InputError::InvalidHeader {
+                                    location: Location::line(line_number),
+                                    reason: format!("costo inválido '{}' para el subconjunto {}", value, subset)
+                                })?;
+                            parsed.push((index, cost));
+                        }
+                        costs = Some((parsed, line_number));
+                    }
+                    "weight" => {
+                        if weights.is_some() {
+                            return Err(InputError::InvalidHeader {
+                                location: Location::line(line_number),
+                                reason: "los pesos ya fueron declarados".to_string()
+                            });
+                        }
+                        let mut parsed: Vec<(String, f64)> = Vec::new();
+                        for (element, value) in self.parse_pairs(values, line_number, offset + name.len() + 2)? {
+                            let weight: f64 = value.parse().ok()
+                                .filter(|weight: &f64| weight.is_finite() && *weight >= 0.0)
+                                .ok_or_else(|| InputError::InvalidHeader {
+                                    location: Location::line(line_number),
+                                    reason: format!("peso inválido '{}' para el elemento {}", value, element)
+                                })?;
+                            parsed.push((element, weight));
+                        }
+                        weights = Some((parsed, line_number));
+                    }
+                    other => {
+                        return Err(InputError::InvalidHeader {
+                            location: Location::line(line_number),
+                            reason: format!("encabezado desconocido '{}'", other)
+                        });
                     }
                 }
+                continue;
+            }
 
-                
-                self.subset.push(unique_parts_ordered);
+            let elements = self.parse_elements(line, line_number, 1)?;
+
+            let mut key: Vec<usize> = elements.iter().map(|element| element_ids.intern(element)).collect();
+            key.sort_unstable();
+            key.dedup();
+            match first_line_of.entry(key) {
+                Entry::Occupied(entry) => {
+                    warnings.push(InputWarning::DuplicateSubset { line: line_number, first_line: *entry.get() });
+                }
+                Entry::Vacant(entry) => {
+                    entry.insert(line_number);
+                }
             }
 
-            
-        } else {
-            return Err(InputError::InvalidFormat("Debe de ser un archivo .txt".to_string()));
-        };
+            subset_lines.push(line_number);
+            on_subset(universe.as_ref().map(|(elements, _)| elements.as_slice()), &elements);
+
+            if verbose && subset_lines.len().is_multiple_of(PROGRESS_INTERVAL) {
+                println!("Leídos {} subconjuntos ({:.1} MB)", subset_lines.len(), bytes_read as f64 / 1.0e6);
+            }
+        }
+
+        if subset_lines.is_empty() {
+            return Err(InputError::EmptyFile { location : Location::default() });
+        }
+        if verbose {
+            println!("Lectura completa: {} subconjuntos en {} líneas ({:.1} MB)",
+                subset_lines.len(), line_number, bytes_read as f64 / 1.0e6);
+        }
+
+        Ok(ParsedInstance { subset_lines, universe, demands, costs, weights, warnings })
+    }
 
-        return Ok(self.subset.clone());
+    fn store_instance(&mut self, parsed : ParsedInstance) {
+        self.subset_lines = parsed.subset_lines;
+        self.universe_line = parsed.universe.as_ref().map(|(_, line)| *line);
+        self.universe = parsed.universe.map(|(elements, _)| elements);
+        self.demand_line = parsed.demands.as_ref().map(|(_, line)| *line);
+        self.demands = parsed.demands.map(|(demands, _)| demands).unwrap_or_default();
+        self.cost_line = parsed.costs.as_ref().map(|(_, line)| *line);
+        self.subset_costs = parsed.costs.map(|(costs, _)| costs).unwrap_or_default();
+        self.weight_line = parsed.weights.as_ref().map(|(_, line)| *line);
+        self.element_weights = parsed.weights.map(|(weights, _)| weights).unwrap_or_default();
+        self.warnings = parsed.warnings;
     }
 
 
@@ -95,14 +555,12 @@ impl ReadInput {
 
         match (pos_s, pos_rs) {
             (Some(_), Some(_)) => {
-                return Err(InputError::InvalidArgumentSeed);
+                Err(InputError::InvalidArgumentSeed)
             }
             (Some(pos), None) => {
-                if self.args.len() < pos + 1 {
-                    return Err(InputError::InvalidFormat("No se encontro el valor de seed".to_string()));
-                }
-
-                let start: i32 = self.args[pos + 1].parse()
+                let start: i32 = self.args.get(pos + 1)
+                    .ok_or_else(|| InputError::missing_value("-s"))?
+                    .parse()
                     .map_err(|_| InputError::InvalidSeed)?;
 
                 if self.args.len() <= pos + 2 || self.args[pos + 2].starts_with('-') {
@@ -120,14 +578,12 @@ impl ReadInput {
 
                 let seeds: Vec<i32> = (start..=end).collect();
                 self.seeds = seeds.clone();
-                return Ok(seeds);
+                Ok(seeds)
             }
             (None, Some(pos)) => {
-                if self.args.len() <= pos + 1 {
-                    return Err(InputError::InvalidFormat("No se encontro el valor de seed".to_string()));
-                }
-
-                let n: usize = self.args[pos + 1].parse()
+                let n: usize = self.args.get(pos + 1)
+                    .ok_or_else(|| InputError::missing_value("-rs"))?
+                    .parse()
                     .map_err(|_| InputError::InvalidSeed)?;
 
                 if n == 0 {
@@ -138,7 +594,7 @@ impl ReadInput {
                 let mut rng = rand::thread_rng();
                 let seeds: Vec<i32> = (0..n).map(|_| rng.r#gen()).collect();
                 self.seeds = seeds.clone();
-                return Ok(seeds);
+                Ok(seeds)
             }
             (None, None) => {
                 Err(InputError::InvalidSeed)
@@ -146,6 +602,76 @@ impl ReadInput {
         }
     }
 
+    pub fn get_universe(&self) -> Option<&Vec<String>> {
+        self.universe.as_ref()
+    }
+
+    pub fn get_demands(&self) -> &[(String, usize)] {
+        &self.demands
+    }
+
+    pub fn get_subset_costs(&self) -> &[(usize, f64)] {
+        &self.subset_costs
+    }
+
+    pub fn get_element_weights(&self) -> &[(String, f64)] {
+        &self.element_weights
+    }
+
+    pub fn get_subset_names(&self) -> &[(usize, String)] {
+        &self.subset_names
+    }
+
+    pub fn get_subset_attributes(&self) -> &[(usize, BTreeMap<String, Value>)] {
+        &self.subset_attributes
+    }
+
+    pub fn get_metadata(&self) -> &BTreeMap<String, Value> {
+        &self.metadata
+    }
+
+    // Línea del archivo a la que se refiere un error de construcción del conjunto
+    pub fn get_error_line(&self, error : &SetError) -> Option<usize> {
+        match error {
+            SetError::EmptySubset { subset }
+            | SetError::EmptyElement { subset }
+            | SetError::UnknownElement { subset, .. }
+            | SetError::ElementOutOfRange { subset, .. } => self.get_subset_line(*subset),
+            SetError::EmptyUniverseElement | SetError::UncoverableElements(_) => self.universe_line,
+            SetError::UndeclaredElement(element) => {
+                if self.element_weights.iter().any(|(name, _)| name == element) {
+                    self.weight_line
+                } else {
+                    self.demand_line
+                }
+            }
+            SetError::InsufficientMultiplicity { .. } => self.demand_line,
+            SetError::UnknownSubset(_) => self.cost_line,
+            SetError::NoSubsets
+            | SetError::InsufficientCoverage { .. }
+            | SetError::EmptySubsetName { .. }
+            | SetError::DuplicateSubsetName { .. } => None,
+        }
+    }
+
+    // Error de la instancia con la ruta y la línea de donde viene, si se conoce
+    pub fn set_error(&self, error : SetError) -> InputError {
+        let location = Location {
+            path : self.instance_path().ok().map(PathBuf::from),
+            line : self.get_error_line(&error),
+            column : None
+        };
+        InputError::InvalidSet { location, source : error }
+    }
+
+    pub fn get_warnings(&self) -> &[InputWarning] {
+        &self.warnings
+    }
+
+    pub fn get_subset_line(&self, subset_index : usize) -> Option<usize> {
+        self.subset_lines.get(subset_index).copied()
+    }
+
     pub fn get_verbose(&self) -> bool {
         self.get_flag("-v")
     }
@@ -154,6 +680,262 @@ impl ReadInput {
         self.get_flag("-svg")
     }
     
+    pub fn get_stats(&self) -> Result<Option<StatsFormat>, InputError> {
+        let position = match self.get_position_flag("-stats") {
+            Some(pos) => pos,
+            None => return Ok(None),
+        };
+
+        match self.args.get(position + 1).map(|s| s.as_str()) {
+            None => Ok(Some(StatsFormat::Table)),
+            Some(value) if value.starts_with('-') => Ok(Some(StatsFormat::Table)),
+            Some("table") => Ok(Some(StatsFormat::Table)),
+            Some("json") => Ok(Some(StatsFormat::Json)),
+            Some(value) => Err(InputError::invalid_argument("-stats", format!("formato de estadísticas desconocido: {}", value))),
+        }
+    }
+
+    // Estrategia de la búsqueda local que se aplica al resultado de cada solver (-polish)
+    pub fn get_polish(&self) -> Result<Option<Strategy>, InputError> {
+        let position = match self.get_position_flag("-polish") {
+            Some(pos) => pos,
+            None => return Ok(None),
+        };
+
+        match self.args.get(position + 1).map(|s| s.as_str()) {
+            None => Ok(Some(Strategy::default())),
+            Some(value) if value.starts_with('-') => Ok(Some(Strategy::default())),
+            Some(value) => Strategy::from_name(value)
+                .map(Some)
+                .ok_or_else(|| InputError::invalid_argument("-polish", format!("estrategia de búsqueda local desconocida: {}", value))),
+        }
+    }
+
+    pub fn get_partial(&self) -> Result<Option<PartialTarget>, InputError> {
+        let position = match self.get_position_flag("-partial") {
+            Some(pos) => pos,
+            None => return Ok(None),
+        };
+
+        let value = self.args.get(position + 1).ok_or_else(|| InputError::missing_value("-partial"))?;
+        let invalid = || InputError::invalid_argument("-partial", format!("valor de cobertura parcial inválido: {}", value));
+
+        let target = if let Some(percent) = value.strip_suffix('%') {
+            let percent: f64 = percent.parse().map_err(|_| invalid())?;
+            PartialTarget::Fraction(percent / 100.0)
+        } else if let Ok(k) = value.parse::<usize>() {
+            PartialTarget::Elements(k)
+        } else {
+            PartialTarget::Fraction(value.parse().map_err(|_| invalid())?)
+        };
+
+        if let PartialTarget::Fraction(fraction) = target
+            && !(fraction > 0.0 && fraction <= 1.0) {
+            return Err(invalid());
+        }
+
+        Ok(Some(target))
+    }
+
+    pub fn get_budget(&self) -> Result<Option<Budget>, InputError> {
+        let subsets = match self.get_position_flag("-budget") {
+            Some(pos) => {
+                let value = self.args.get(pos + 1)
+                    .ok_or_else(|| InputError::missing_value("-budget"))?;
+                let limit: usize = value.parse()
+                    .map_err(|_| InputError::invalid_argument("-budget", format!("presupuesto inválido: {}", value)))?;
+                Some(Budget::Subsets(limit))
+            }
+            None => None,
+        };
+
+        let cost = match self.get_position_flag("-budget-cost") {
+            Some(pos) => {
+                let value = self.args.get(pos + 1)
+                    .ok_or_else(|| InputError::missing_value("-budget-cost"))?;
+                let limit: f64 = value.parse().ok()
+                    .filter(|limit: &f64| limit.is_finite() && *limit >= 0.0)
+                    .ok_or_else(|| InputError::invalid_argument("-budget-cost", format!("presupuesto inválido: {}", value)))?;
+                Some(Budget::Cost(limit))
+            }
+            None => None,
+        };
+
+        match (subsets, cost) {
+            (Some(_), Some(_)) => Err(InputError::invalid_argument("-budget-cost", "no se puede usar junto con -budget")),
+            (budget, None) | (None, budget) => Ok(budget),
+        }
+    }
+
+    pub fn get_objective(&self) -> Result<Arc<dyn Objective>, InputError> {
+        let name = match self.get_position_flag("-objective") {
+            Some(pos) => self.args.get(pos + 1)
+                .ok_or_else(|| InputError::missing_value("-objective"))?
+                .as_str(),
+            None => "default",
+        };
+
+        objective_from_name(name).ok_or_else(|| InputError::invalid_argument("-objective", format!(
+            "función objetivo desconocida: {} (disponibles: {})", name, OBJECTIVE_NAMES.join(", ")
+        )))
+    }
+
+    pub fn get_solver(&self, registry : &SolverRegistry) -> Result<Box<dyn Solver>, InputError> {
+        let name = match self.get_position_flag("-solver") {
+            Some(pos) => self.args.get(pos + 1)
+                .ok_or_else(|| InputError::missing_value("-solver"))?
+                .as_str(),
+            None => DEFAULT_SOLVER,
+        };
+
+        registry.create(name).ok_or_else(|| InputError::invalid_argument("-solver", format!(
+            "solver desconocido: {} (disponibles: {})", name, registry.names().join(", ")
+        )))
+    }
+
+    // Solo los criterios indicados; si ninguno garantiza el final, el solver agrega el máximo de
+    // iteraciones por defecto (Termination::bounded)
+    pub fn get_termination(&self) -> Result<Termination, InputError> {
+        let mut termination = Termination::none();
+        if let Some(seconds) = self.get_number_flag("-time", |seconds : &f64| seconds.is_finite() && *seconds > 0.0)? {
+            termination = termination.with_time_limit(Duration::from_secs_f64(seconds));
+        }
+        if let Some(iterations) = self.get_number_flag("-iterations", |iterations : &usize| *iterations > 0)? {
+            termination = termination.with_max_iterations(iterations);
+        }
+        if let Some(iterations) = self.get_number_flag("-stagnation", |iterations : &usize| *iterations > 0)? {
+            termination = termination.with_max_stagnation(iterations);
+        }
+        if let Some(cost) = self.get_number_flag("-target", |cost : &f64| cost.is_finite())? {
+            termination = termination.with_target_cost(cost);
+        }
+        if let Some(bound) = self.get_number_flag("-lower-bound", |bound : &f64| bound.is_finite())? {
+            termination = termination.with_lower_bound(bound);
+        }
+        Ok(termination)
+    }
+
+    // Formato del archivo de -c (-from); por defecto txt
+    pub fn get_input_format(&self) -> Result<InstanceFormat, InputError> {
+        self.get_format_flag("-from").map(|format| format.unwrap_or_default())
+    }
+
+    // Delimitador (-delimiter), comillas (-quote, o none), escape (-escape) y encabezado (-header)
+    // de los archivos csv
+    pub fn get_csv_options(&self) -> Result<CsvOptions, InputError> {
+        let defaults = CsvOptions::default();
+        let quote = match self.get_position_flag("-quote").and_then(|pos| self.args.get(pos + 1)) {
+            Some(value) if value == "none" => None,
+            _ => self.get_char_flag("-quote")?.or(defaults.quote),
+        };
+        Ok(CsvOptions {
+            delimiter : self.get_char_flag("-delimiter")?.unwrap_or(defaults.delimiter),
+            quote,
+            escape : self.get_char_flag("-escape")?,
+            header : self.get_flag("-header")
+        })
+    }
+
+    // Tabla subconjunto,costo que acompaña a una lista de aristas csv (-costs)
+    pub fn get_cost_table(&self) -> Result<Option<String>, InputError> {
+        match self.get_position_flag("-costs") {
+            Some(pos) => self.args.get(pos + 1)
+                .filter(|path| !path.starts_with('-'))
+                .cloned()
+                .map(Some)
+                .ok_or_else(|| InputError::missing_value("-costs")),
+            None => Ok(None),
+        }
+    }
+
+    // Archivo y formato de destino de -convert (-to, por defecto txt)
+    pub fn get_convert(&self) -> Result<Option<(String, InstanceFormat)>, InputError> {
+        let path = match self.get_position_flag("-convert") {
+            Some(pos) => self.args.get(pos + 1)
+                .filter(|path| !path.starts_with('-'))
+                .ok_or_else(|| InputError::missing_value("-convert"))?,
+            None => return Ok(None),
+        };
+        let format = self.get_format_flag("-to")?.unwrap_or_default();
+        Ok(Some((path.clone(), format)))
+    }
+
+    // Índices de los subconjuntos de una solución previa (-initial), sin validar contra la instancia
+    pub fn get_initial_solution(&self) -> Result<Option<Vec<usize>>, InputError> {
+        let path = match self.get_position_flag("-initial") {
+            Some(pos) => self.args.get(pos + 1)
+                .filter(|path| !path.starts_with('-'))
+                .ok_or_else(|| InputError::missing_value("-initial"))?,
+            None => return Ok(None),
+        };
+
+        let file = File::open(path).map_err(|e| InputError::io(path, e))?;
+        parse_solution(BufReader::new(file)).map(Some).map_err(|e| e.in_file(path))
+    }
+
+    // Archivo y frecuencia (en iteraciones) de los puntos de control
+    pub fn get_checkpoint(&self) -> Result<Option<(String, usize)>, InputError> {
+        let interval = self.get_number_flag("-checkpoint-every", |interval : &usize| *interval > 0)?;
+        let path = match self.get_position_flag("-checkpoint") {
+            Some(pos) => self.args.get(pos + 1)
+                .filter(|path| !path.starts_with('-'))
+                .ok_or_else(|| InputError::missing_value("-checkpoint"))?,
+            None if interval.is_some() => {
+                return Err(InputError::invalid_argument("-checkpoint-every", "requiere -checkpoint"));
+            }
+            None => return Ok(None),
+        };
+        Ok(Some((path.clone(), interval.unwrap_or(DEFAULT_CHECKPOINT_INTERVAL))))
+    }
+
+    pub fn get_resume(&self) -> Result<Option<String>, InputError> {
+        match self.get_position_flag("--resume").or_else(|| self.get_position_flag("-resume")) {
+            Some(pos) => self.args.get(pos + 1)
+                .filter(|path| !path.starts_with('-'))
+                .cloned()
+                .map(Some)
+                .ok_or_else(|| InputError::missing_value("--resume")),
+            None => Ok(None),
+        }
+    }
+
+    pub fn get_element_order(&self) -> Result<ElementOrder, InputError> {
+        let name = match self.get_position_flag("-order") {
+            Some(pos) => self.args.get(pos + 1)
+                .ok_or_else(|| InputError::missing_value("-order"))?
+                .as_str(),
+            None => return Ok(ElementOrder::default()),
+        };
+
+        ElementOrder::from_name(name).ok_or_else(|| InputError::invalid_argument("-order", format!(
+            "orden de elementos desconocido: {} (disponibles: first-seen, lexicographic, natural)", name
+        )))
+    }
+
+    pub fn get_mode_request(&self) -> Result<ModeRequest, InputError> {
+        let mut requests: Vec<ModeRequest> = Vec::new();
+        if let Some(target) = self.get_partial()? {
+            requests.push(ModeRequest::Partial(target));
+        }
+        if self.get_flag("-multicover") {
+            requests.push(ModeRequest::MultiCover);
+        }
+        if self.get_flag("-partition") {
+            requests.push(ModeRequest::Partition);
+        }
+        if let Some(budget) = self.get_budget()? {
+            requests.push(ModeRequest::MaxCoverage(budget));
+        }
+
+        match requests.as_slice() {
+            [] => Ok(ModeRequest::Full),
+            [request] => Ok(*request),
+            _ => Err(InputError::invalid_argument(
+                "-partial/-multicover/-partition/-budget", "solo se puede indicar uno de ellos"
+            )),
+        }
+    }
+
     pub fn get_help(&self) -> bool {
         self.get_flag("-h") || self.get_flag("--help")
     }
@@ -164,8 +946,35 @@ impl ReadInput {
         println!("Opciones:");
         println!("  -h, --help         Muestra esta ayuda y termina");
         println!("  -v                 Activa el modo verbose");
-        println!("  -c <path>          Ruta explícita del archivo .txt que representa los subconjuntos del universo");
+        println!("  -c <path>          Ruta explícita del archivo .txt, .json o .csv según -from (o comprimido .gz) con los subconjuntos del universo");
         println!("  -svg               Activa el modo de salida SVG");
+        println!("  -from <fmt>        Formato del archivo de -c: txt (por defecto), orlib, json o csv");
+        println!("  -costs <path>      Tabla subconjunto,costo de una instancia csv");
+        println!("  -delimiter <c>     Separador de los archivos csv (por defecto ',', tab para tabulador)");
+        println!("  -quote <c|none>    Comillas de los archivos csv (por defecto '\"')");
+        println!("  -escape <c>        Escape dentro de comillas en csv (por defecto se duplica la comilla)");
+        println!("  -header            La primera fila de los archivos csv es un encabezado");
+        println!("  -convert <path>    Escribe la instancia en path con el formato de -to y termina");
+        println!("  -to <fmt>          Formato de -convert: txt (por defecto), orlib, json o csv");
+        println!("  -partial <k|f|p%>  Cobertura parcial: cubrir al menos k elementos o una fracción de ellos (ej: -partial 95%)");
+        println!("  -multicover        Multicobertura: cada elemento debe cubrirse tantas veces como su demanda (#demand)");
+        println!("  -partition         Partición: cada elemento debe cubrirse exactamente una vez");
+        println!("  -budget <B>        Cobertura máxima eligiendo a lo más B subconjuntos");
+        println!("  -budget-cost <C>   Cobertura máxima con costo total a lo más C (costos en #cost)");
+        println!("  -solver <name>     Solver: greedy, rfd o local (por defecto rfd)");
+        println!("  -polish [first|best] Aplica búsqueda local al resultado (primera o mejor mejora)");
+        println!("  -time <s>          Detiene la búsqueda tras s segundos");
+        println!("  -iterations <n>    Máximo de iteraciones (200 si no se indica -time ni -stagnation)");
+        println!("  -stagnation <n>    Detiene la búsqueda tras n iteraciones sin mejora");
+        println!("  -target <c>        Detiene la búsqueda en cuanto el costo sea a lo más c");
+        println!("  -lower-bound <c>   Cota inferior conocida del costo; al alcanzarla la solución es óptima");
+        println!("  -checkpoint <path> Guarda puntos de control de la búsqueda en path");
+        println!("  -checkpoint-every <n> Iteraciones entre puntos de control (por defecto 50)");
+        println!("  --resume <path>    Continúa la búsqueda desde un punto de control");
+        println!("  -initial <path>    Solución inicial: índices de subconjunto (3 o S3) o el JSON de un resumen");
+        println!("  -objective <name>  Función objetivo: default, cardinality, weighted, adaptive, squared o lexicographic");
+        println!("  -order <policy>    Índices de los elementos: first-seen (por defecto), lexicographic o natural");
+        println!("  -stats [table|json] Muestra las estadísticas de la instancia como tabla (por defecto) o JSON");
         println!("  -s <I> <F>         Genera semillas en el rango [I, F]");
         println!("  -s <n>             Inicializa con la semilla n");
         println!("  -rs <n>            Genera n semillas aleatorias");
@@ -179,8 +988,124 @@ impl ReadInput {
         self.args.iter().position(|arg| arg == flag)
     }
 
-    fn get_element(&self, s: &str) -> Result<String, InputError> {
+    fn get_format_flag(&self, flag : &str) -> Result<Option<InstanceFormat>, InputError> {
+        let pos = match self.get_position_flag(flag) {
+            Some(pos) => pos,
+            None => return Ok(None),
+        };
+        let name = self.args.get(pos + 1)
+            .ok_or_else(|| InputError::missing_value(flag))?;
+        InstanceFormat::from_name(name)
+            .map(Some)
+            .ok_or_else(|| InputError::invalid_argument(flag, format!("formato de instancia desconocido: {}", name)))
+    }
+
+    // Un carácter ASCII; "tab" y "\t" son el tabulador
+    fn get_char_flag(&self, flag : &str) -> Result<Option<u8>, InputError> {
+        let pos = match self.get_position_flag(flag) {
+            Some(pos) => pos,
+            None => return Ok(None),
+        };
+        let value = self.args.get(pos + 1)
+            .ok_or_else(|| InputError::missing_value(flag))?;
+        match value.as_str() {
+            "tab" | "\\t" => Ok(Some(b'\t')),
+            _ if value.len() == 1 && value.is_ascii() => Ok(Some(value.as_bytes()[0])),
+            _ => Err(InputError::invalid_argument(flag, format!("se espera un carácter y se encontró '{}'", value))),
+        }
+    }
+
+    fn get_number_flag<T : FromStr>(&self, flag : &str, is_valid : impl Fn(&T) -> bool) -> Result<Option<T>, InputError> {
+        let pos = match self.get_position_flag(flag) {
+            Some(pos) => pos,
+            None => return Ok(None),
+        };
+        let value = self.args.get(pos + 1)
+            .ok_or_else(|| InputError::missing_value(flag))?;
+        value.parse().ok()
+            .filter(|number| is_valid(number))
+            .map(Some)
+            .ok_or_else(|| InputError::invalid_argument(flag, format!("valor inválido: {}", value)))
+    }
+
+    // Elementos de una línea en el orden del archivo, sin copiarlos; los repetidos se quitan al
+    // construir el conjunto
+    fn parse_elements<'a>(&self, line : &'a str, line_number : usize, first_column : usize) -> Result<Vec<&'a str>, InputError> {
+        let mut elements: Vec<&str> = Vec::new();
+        let mut column = first_column;
+
+        for part in line.split(",") {
+            elements.push(self.get_element(part, Location::at(line_number, column))?);
+            column += part.chars().count() + 1;
+        }
+
+        Ok(elements)
+    }
+
+    fn parse_pairs(&self, line : &str, line_number : usize, first_column : usize) -> Result<Vec<(String, String)>, InputError> {
+        let mut pairs: Vec<(String, String)> = Vec::new();
+        let mut column = first_column;
+
+        for part in line.split(",") {
+            let (element, value) = part.split_once('=').ok_or_else(|| InputError::InvalidHeader {
+                location: Location::at(line_number, column),
+                reason: "se esperaba '<elemento>=<valor>'".to_string()
+            })?;
+            let element = self.get_element(element, Location::at(line_number, column))?;
+            column += part.chars().count() + 1;
+
+            pairs.push((element.to_string(), value.trim().to_string()));
+        }
+
+        Ok(pairs)
+    }
+
+    fn get_element<'a>(&self, s: &'a str, location : Location) -> Result<&'a str, InputError> {
         let trimmed = s.trim();
-        Ok(trimmed.to_string())
+        if trimmed.is_empty() {
+            return Err(InputError::EmptyElement { location });
+        }
+        Ok(trimmed)
     }
-}
\ No newline at end of file
+}
+
+// Una solución es una lista de índices de subconjunto ("3" o "S3") separados por comas o espacios,
+// con comentarios que empiezan con '#'. También se acepta el JSON del resumen de una cobertura,
+// del que se toma el campo "subsets".
+pub fn parse_solution<R : BufRead>(mut reader : R) -> Result<Vec<usize>, InputError> {
+    let mut content = String::new();
+    reader.read_to_string(&mut content)
+        .map_err(|source| InputError::Io { location : Location::default(), source })?;
+
+    if content.trim_start().starts_with('{') {
+        let report: serde_json::Value = serde_json::from_str(&content)
+            .map_err(|e| {
+                let location = Location::at(e.line(), e.column());
+                InputError::parse_with(location, "solución JSON inválida", e)
+            })?;
+        return report.get("subsets")
+            .and_then(|subsets| subsets.as_array())
+            .ok_or_else(|| InputError::validation(Location::default(), "la solución JSON no tiene el campo \"subsets\""))?
+            .iter()
+            .map(|index| index.as_u64().map(|index| index as usize)
+                .ok_or_else(|| InputError::validation(Location::default(), format!("índice de subconjunto inválido: {}", index))))
+            .collect();
+    }
+
+    let mut subsets: Vec<usize> = Vec::new();
+    for (number, line) in content.lines().enumerate() {
+        let data = line.split('#').next().unwrap_or("");
+        for token in data.split(|c : char| c == ',' || c.is_whitespace()).filter(|token| !token.is_empty()) {
+            let digits = token.strip_prefix('S').unwrap_or(token);
+            let index: usize = digits.parse().map_err(|e| InputError::parse_with(
+                Location::line(number + 1), format!("índice de subconjunto inválido '{}'", token), e
+            ))?;
+            subsets.push(index);
+        }
+    }
+
+    if subsets.is_empty() {
+        return Err(InputError::EmptyFile { location : Location::default() });
+    }
+    Ok(subsets)
+}
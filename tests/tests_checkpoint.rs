@@ -0,0 +1,177 @@
+#[cfg(test)]
+mod test {
+    use rand::{RngCore, SeedableRng};
+    use rand::rngs::StdRng;
+    use rand_chacha::ChaCha12Rng;
+    use tempfile::tempdir;
+
+    use set_covering_problem::entity::cover_mode::CoverMode;
+    use set_covering_problem::entity::objective::objective_from_name;
+    use set_covering_problem::entity::set::Set;
+    use set_covering_problem::entity::subset_cover::SubsetCover;
+    use set_covering_problem::solver::checkpoint::{Checkpoint, CheckpointWriter, RngState};
+    use set_covering_problem::solver::greedy::GreedySolver;
+    use set_covering_problem::solver::observer::{ObserverGroup, TraceObserver};
+    use set_covering_problem::solver::rfd::RfdSolver;
+    use set_covering_problem::solver::termination::Termination;
+    use set_covering_problem::solver::{NoopObserver, Solver, SolverConfig, SolverObserver, SolverResult};
+
+    fn to_subsets(data : &[&[&str]]) -> Vec<Vec<String>> {
+        data.iter()
+            .map(|subset| subset.iter().map(|e| e.to_string()).collect())
+            .collect()
+    }
+
+    fn setup_test_set() -> Set {
+        Set::new(to_subsets(&[
+            &["a", "b"],             // S0
+            &["c", "d"],             // S1
+            &["e", "f"],             // S2
+            &["a", "b", "c"],        // S3
+            &["d", "e", "f"],        // S4
+            &["b", "d"],             // S5
+            &["a", "f"],             // S6
+            &["c", "e"],             // S7
+        ]))
+    }
+
+    // Objetivo adaptativo y reinicios para que el estado a restaurar no sea trivial
+    fn config(iterations : usize) -> SolverConfig {
+        SolverConfig {
+            termination : Termination::none().with_max_iterations(iterations),
+            drops : 4,
+            restart_after : 3,
+            checkpoint_interval : 7,
+            ..SolverConfig::new(CoverMode::Full, objective_from_name("adaptive").unwrap())
+        }
+    }
+
+    #[derive(Default)]
+    struct Collector {
+        checkpoints : Vec<Checkpoint>
+    }
+
+    impl SolverObserver for Collector {
+        fn on_checkpoint(&mut self, checkpoint : &Checkpoint, _best : &SubsetCover) {
+            self.checkpoints.push(checkpoint.clone());
+        }
+    }
+
+    fn run(solver : &mut RfdSolver, set : &Set, config : &SolverConfig, observer : &mut dyn SolverObserver) -> SolverResult {
+        let mut rng = StdRng::seed_from_u64(11);
+        solver.solve(set, config, &mut rng, observer)
+    }
+
+    #[test]
+    fn test_rng_state_round_trip() {
+        let mut rng = ChaCha12Rng::seed_from_u64(3);
+        for _ in 0..5 {
+            rng.next_u32();
+        }
+        let mut restored = RngState::capture(&rng).restore();
+        let expected: Vec<u64> = (0..10).map(|_| rng.next_u64()).collect();
+        let found: Vec<u64> = (0..10).map(|_| restored.next_u64()).collect();
+        assert_eq!(expected, found);
+    }
+
+    #[test]
+    fn test_resume_follows_same_trajectory() {
+        let set = setup_test_set();
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("rfd.json");
+
+        let mut full_trace = TraceObserver::new();
+        let full = run(&mut RfdSolver::new(), &set, &config(40), &mut full_trace);
+
+        // La corrida interrumpida deja su último punto de control en disco
+        let mut collector = Collector::default();
+        let mut writer = CheckpointWriter::new(&path);
+        {
+            let mut observers = ObserverGroup::new();
+            observers.push(&mut collector);
+            observers.push(&mut writer);
+            run(&mut RfdSolver::new(), &set, &config(17), &mut observers);
+        }
+        assert_eq!(collector.checkpoints.iter().map(|c| c.iteration).collect::<Vec<_>>(), vec![7, 14, 17]);
+        assert_eq!(writer.saved, 3);
+        assert!(writer.last_error.is_none());
+
+        let saved = Checkpoint::load(&path).unwrap();
+        assert_eq!(&saved, collector.checkpoints.last().unwrap());
+
+        let resumed_config = config(40);
+        saved.validate("rfd", &set, &resumed_config).unwrap();
+        let mut solver = RfdSolver::new();
+        solver.resume_from(saved).unwrap();
+        let mut resumed_trace = TraceObserver::new();
+        let resumed = run(&mut solver, &set, &resumed_config, &mut resumed_trace);
+
+        assert_eq!(resumed.cover.elements, full.cover.elements);
+        assert_eq!(resumed.cover.cost, full.cover.cost);
+        assert_eq!(resumed.stats.iterations, full.stats.iterations);
+        assert_eq!(resumed.stats.evaluations, full.stats.evaluations);
+        assert_eq!(resumed.stats.best_iteration, full.stats.best_iteration);
+        assert_eq!(resumed.stats.restarts, full.stats.restarts);
+        assert_eq!(resumed_trace.points, full_trace.points[17..].to_vec());
+    }
+
+    #[test]
+    fn test_checkpoint_floats_round_trip_exactly() {
+        let set = setup_test_set();
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("floats.json");
+        let mut collector = Collector::default();
+        run(&mut RfdSolver::new(), &set, &config(7), &mut collector);
+
+        // Valores arbitrarios: un solo bit distinto al cargarlos cambia la trayectoria
+        let mut rng = ChaCha12Rng::seed_from_u64(5);
+        let mut random = || 0.01 + 0.99 * (rng.next_u64() >> 11) as f64 / (1u64 << 53) as f64;
+        let mut checkpoint = collector.checkpoints.pop().unwrap();
+        checkpoint.altitudes = (0..2000).map(|_| random()).collect();
+        checkpoint.objective_state = (0..2000).map(|_| random() * 1e6).collect();
+        checkpoint.best_cost = random();
+        checkpoint.best_cover_cost = random();
+        checkpoint.elapsed_secs = random();
+        checkpoint.save(&path).unwrap();
+
+        let loaded = Checkpoint::load(&path).unwrap();
+        let bits = |values : &[f64]| values.iter().map(|value| value.to_bits()).collect::<Vec<u64>>();
+        assert_eq!(bits(&loaded.altitudes), bits(&checkpoint.altitudes));
+        assert_eq!(bits(&loaded.objective_state), bits(&checkpoint.objective_state));
+        assert_eq!(loaded.best_cost.to_bits(), checkpoint.best_cost.to_bits());
+        assert_eq!(loaded.best_cover_cost.to_bits(), checkpoint.best_cover_cost.to_bits());
+        assert_eq!(loaded, checkpoint);
+    }
+
+    #[test]
+    fn test_checkpoint_validation() {
+        let set = setup_test_set();
+        let mut collector = Collector::default();
+        run(&mut RfdSolver::new(), &set, &config(7), &mut collector);
+        let checkpoint = collector.checkpoints.pop().unwrap();
+
+        assert!(checkpoint.validate("rfd", &set, &config(7)).is_ok());
+        assert!(checkpoint.validate("greedy", &set, &config(7)).is_err());
+        assert!(checkpoint.validate("rfd", &set, &SolverConfig::default()).is_err());
+
+        let smaller = Set::new(to_subsets(&[&["a", "b"], &["c"]]));
+        assert!(checkpoint.validate("rfd", &smaller, &SolverConfig { objective : config(7).objective, ..SolverConfig::default() }).is_err());
+
+        let mut broken = checkpoint.clone();
+        broken.best = vec![99];
+        assert!(broken.validate("rfd", &set, &config(7)).is_err());
+
+        assert!(GreedySolver.resume_from(checkpoint).is_err());
+        assert!(Checkpoint::load("no_existe.json").is_err());
+    }
+
+    #[test]
+    fn test_no_checkpoints_by_default() {
+        let set = setup_test_set();
+        let mut collector = Collector::default();
+        let config = SolverConfig { checkpoint_interval : 0, ..config(10) };
+        run(&mut RfdSolver::new(), &set, &config, &mut collector);
+        assert!(collector.checkpoints.is_empty());
+        run(&mut RfdSolver::new(), &set, &config, &mut NoopObserver);
+    }
+}
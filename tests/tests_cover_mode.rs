@@ -0,0 +1,233 @@
+#[cfg(test)]
+mod test {
+    use set_covering_problem::entity::cover_mode::{CoverMode, PartialTarget};
+    use set_covering_problem::entity::set::{Set, SetError};
+    use set_covering_problem::entity::overlap::OverlapAnalysis;
+    use set_covering_problem::entity::max_coverage::Budget;
+    use set_covering_problem::entity::subset_cover::SubsetCover;
+
+    // Universal Set U = {a, b, c, d, e}
+    fn setup_test_set() -> Set {
+        let subsets_data = vec![
+            vec!["a".to_string(), "b".to_string()],                     // S0
+            vec!["c".to_string(), "d".to_string()],                     // S1
+            vec!["e".to_string()],                                      // S2
+            vec!["a".to_string(), "c".to_string()],                     // S3
+            vec!["a".to_string(), "b".to_string(), "c".to_string(), "d".to_string(), "e".to_string()], // S4
+        ];
+        Set::new(subsets_data)
+    }
+
+    // ====================================================================
+    // 1. Cobertura parcial
+    // ====================================================================
+
+    #[test]
+    fn test_partial_target_resolve() {
+        let set = setup_test_set();
+
+        assert_eq!(PartialTarget::Elements(3).resolve(&set), Ok(CoverMode::Partial(3)));
+        // ceil(0.7 * 5) = 4
+        assert_eq!(PartialTarget::Fraction(0.7).resolve(&set), Ok(CoverMode::Partial(4)));
+        assert!(PartialTarget::Elements(6).resolve(&set).is_err(), "k no puede exceder |X|");
+    }
+
+    #[test]
+    fn test_partial_fraction_rounding() {
+        // 100 elementos: p% son exactamente p, aunque p / 100 * 100 no sea entero en punto flotante
+        let set = Set::from_indices(100, (0..100).map(|element| vec![element]).collect()).unwrap();
+        for percent in [7, 14, 28, 29, 55, 57, 95, 100] {
+            let target = PartialTarget::Fraction(percent as f64 / 100.0);
+            assert_eq!(target.resolve(&set), Ok(CoverMode::Partial(percent)), "{}%", percent);
+        }
+        // Una fracción que no da un entero se sigue redondeando hacia arriba
+        assert_eq!(PartialTarget::Fraction(0.071).resolve(&set), Ok(CoverMode::Partial(8)));
+        assert_eq!(PartialTarget::Fraction(0.0).resolve(&set), Ok(CoverMode::Partial(0)));
+    }
+
+    #[test]
+    fn test_partial_feasibility() {
+        let set = setup_test_set();
+        let mode = CoverMode::Partial(4);
+
+        assert!(set.is_partial_cover_valid(&[0, 1], 4), "S0 y S1 cubren 4 elementos");
+        assert!(mode.is_feasible(&[0, 1], &set));
+        assert!(!mode.is_feasible(&[0, 2], &set), "S0 y S2 solo cubren 3 elementos");
+        assert!(!CoverMode::Full.is_feasible(&[0, 1], &set));
+    }
+
+    #[test]
+    fn test_partial_cost_only_charges_shortfall() {
+        let set = setup_test_set();
+        let mode = CoverMode::Partial(4);
+
+        // Cubre 4 >= k: sin penalización. Costo = 2 / 5 = 0.4
+        assert_eq!(SubsetCover::calculate_cost_mode(&[0, 1], &set, &mode), 0.4);
+
+        // Cubre 3, falta 1. Costo = (2 + 1 * 5 * 5) / 5 = 5.4
+        assert_eq!(SubsetCover::calculate_cost_mode(&[0, 2], &set, &mode), 5.4);
+
+        let mut cover = SubsetCover::with_mode(&set, mode);
+        cover.add_subset(0, &set).unwrap();
+        cover.add_subset(1, &set).unwrap();
+        assert!(cover.is_feasible(&set));
+        assert_eq!(cover.cost, 0.4);
+    }
+
+    #[test]
+    fn test_partial_check_feasible_with_uncoverable_universe() {
+        let universe: Vec<String> = ["a", "b", "c", "z"].iter().map(|e| e.to_string()).collect();
+        let subsets = vec![vec!["a".to_string(), "b".to_string()], vec!["c".to_string()]];
+        let set = Set::from_universe(universe, subsets).unwrap();
+
+        assert!(CoverMode::Full.check_feasible(&set).is_err());
+        assert!(CoverMode::Partial(3).check_feasible(&set).is_ok(), "3 de 4 elementos son cubribles");
+        assert_eq!(
+            CoverMode::Partial(4).check_feasible(&set),
+            Err(SetError::InsufficientCoverage { required: 4, coverable: 3 })
+        );
+    }
+
+    // ====================================================================
+    // 2. Multicobertura
+    // ====================================================================
+
+    fn setup_demand_set() -> Set {
+        let mut set = setup_test_set();
+        // a aparece en S0, S3 y S4; c aparece en S1, S3 y S4
+        set.set_demands(&[("a".to_string(), 2), ("c".to_string(), 3)]).unwrap();
+        set
+    }
+
+    #[test]
+    fn test_demand_shortfall() {
+        let set = setup_demand_set();
+
+        assert_eq!(set.coverage_counts(&[0, 4]).unwrap(), vec![2, 2, 1, 1, 1]);
+        assert_eq!(set.demand_shortfall(&[0, 4]).unwrap(), vec![("c".to_string(), 2)]);
+        assert_eq!(
+            set.demand_shortfall(&[]).unwrap().len(), 5,
+            "Sin subconjuntos todos los elementos quedan cortos"
+        );
+        assert!(set.is_multicover_valid(&[0, 1, 3, 4]));
+        assert!(!set.is_multicover_valid(&[0, 3, 4]), "b, d y e se cubren pero c solo dos veces");
+    }
+
+    #[test]
+    fn test_multicover_cost_and_feasibility() {
+        let set = setup_demand_set();
+        let mode = CoverMode::MultiCover;
+
+        // {S0, S4}: faltan 2 coberturas de c. Costo = (2 + 2 * 5 * 5) / 5 = 10.4
+        assert_eq!(SubsetCover::calculate_cost_mode(&[0, 4], &set, &mode), 10.4);
+        // {S0, S1, S3, S4}: factible. Costo = 4 / 5 = 0.8
+        assert_eq!(SubsetCover::calculate_cost_mode(&[0, 1, 3, 4], &set, &mode), 0.8);
+        assert!(mode.check_feasible(&set).is_ok());
+
+        let mut set = setup_test_set();
+        set.set_demands(&[("e".to_string(), 3)]).unwrap();
+        assert_eq!(
+            mode.check_feasible(&set),
+            Err(SetError::InsufficientMultiplicity { element: "e".to_string(), demand: 3, available: 2 })
+        );
+        assert_eq!(
+            set.set_demands(&[("z".to_string(), 1)]),
+            Err(SetError::UndeclaredElement("z".to_string()))
+        );
+    }
+
+    // ====================================================================
+    // 3. Partición
+    // ====================================================================
+
+    #[test]
+    fn test_partition_validity() {
+        let set = setup_test_set();
+
+        assert!(set.is_partition_valid(&[0, 1, 2]), "S0, S1 y S2 particionan U");
+        assert!(set.is_partition_valid(&[4]));
+        assert!(!set.is_partition_valid(&[0, 1, 2, 3]), "S3 repite a y c");
+        assert!(!set.is_partition_valid(&[0, 1]), "Falta e");
+
+        let pairs: Vec<(usize, usize)> = OverlapAnalysis::new(&[0, 1, 3], &set).unwrap()
+            .overlapping_pairs().into_iter().map(|(i, j, _)| (i, j)).collect();
+        assert_eq!(pairs, vec![(0, 3), (1, 3)]);
+        assert_eq!(set.partition_violation(&[0, 1, 2, 3]).unwrap(), (2, 0));
+        assert_eq!(set.partition_violation(&[0, 3]).unwrap(), (1, 2));
+    }
+
+    #[test]
+    fn test_partition_cost_charges_over_and_under() {
+        let set = setup_test_set();
+        let mode = CoverMode::Partition;
+
+        // Factible. Costo = 3 / 5 = 0.6
+        assert_eq!(SubsetCover::calculate_cost_mode(&[0, 1, 2], &set, &mode), 0.6);
+        // Sobrecobertura 2. Costo = (4 + 2 * 5 * 5) / 5 = 10.8
+        assert_eq!(SubsetCover::calculate_cost_mode(&[0, 1, 2, 3], &set, &mode), 10.8);
+        // Sobrecobertura 1 y subcobertura 2. Costo = (2 + 3 * 5 * 5) / 5 = 15.4
+        assert_eq!(SubsetCover::calculate_cost_mode(&[0, 3], &set, &mode), 15.4);
+    }
+
+    #[test]
+    fn test_repair() {
+        let set = setup_test_set();
+
+        // S3 sobrecubre a y c; se elimina y se completa con S1 y S2.
+        let repaired = CoverMode::Partition.repair(&[0, 3], &set);
+        assert!(set.is_partition_valid(&repaired), "Reparación no factible: {:?}", repaired);
+
+        let repaired = CoverMode::Partition.repair(&[0, 1, 2, 3, 4], &set);
+        assert!(set.is_partition_valid(&repaired), "Reparación no factible: {:?}", repaired);
+
+        // En cobertura completa se agrega el subconjunto que más reduce el faltante.
+        assert_eq!(CoverMode::Full.repair(&[0], &set), vec![0, 4]);
+        assert_eq!(CoverMode::Partial(4).repair(&[0, 1], &set), vec![0, 1]);
+
+        // S4 aporta más demanda pendiente; después c necesita dos coberturas más (S1 y S3)
+        let set = setup_demand_set();
+        let repaired = CoverMode::MultiCover.repair(&[0], &set);
+        assert_eq!(repaired, vec![0, 1, 3, 4]);
+        assert!(set.is_multicover_valid(&repaired));
+    }
+
+    #[test]
+    fn test_marginal_gains_match_shortfall() {
+        let mut set = setup_demand_set();
+        set.set_element_weights(&[("a".to_string(), 3.0)]).unwrap();
+
+        let modes = [CoverMode::Full, CoverMode::Partial(3), CoverMode::MultiCover, CoverMode::Partition];
+        for mode in modes.iter() {
+            let selected = vec![0];
+            let gains = mode.marginal_gains(&selected, &set).unwrap();
+            let current = mode.shortfall(&selected, &set).unwrap();
+            for (candidate, gain) in gains.iter().enumerate().skip(1) {
+                let after = mode.shortfall(&[0, candidate], &set).unwrap();
+                assert!((gain - (current - after)).abs() < 1e-9, "{} con S{}", mode, candidate);
+            }
+            assert_eq!(gains[0], 0.0);
+        }
+    }
+
+    #[test]
+    fn test_counts_variants_match_allocating_ones() {
+        let set = setup_demand_set();
+        let modes = [
+            CoverMode::Full, CoverMode::Partial(4), CoverMode::MultiCover, CoverMode::Partition,
+            CoverMode::MaxCoverage(Budget::Subsets(2)), CoverMode::MaxCoverage(Budget::Cost(2.0)),
+        ];
+        let families: [&[usize]; 5] = [&[], &[0, 1, 2], &[0, 3], &[4], &[0, 3, 4, 1]];
+
+        let mut counts = vec![9; 1];
+        let mut gains = vec![f64::NAN; 1];
+        for mode in modes.iter() {
+            for family in families.iter() {
+                set.coverage_counts_into(family, &mut counts).unwrap();
+                assert_eq!(mode.is_feasible_with_counts(family, &counts, &set), mode.is_feasible(family, &set), "{} con {:?}", mode, family);
+
+                mode.marginal_gains_into(family, &counts, &set, &mut gains);
+                assert_eq!(gains, mode.marginal_gains(family, &set).unwrap(), "{} con {:?}", mode, family);
+            }
+        }
+    }
+}
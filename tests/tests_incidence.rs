@@ -0,0 +1,34 @@
+#[cfg(test)]
+mod test {
+    use set_covering_problem::entity::incidence::Incidence;
+
+    #[test]
+    fn test_transpose() {
+        // 3 filas x 4 columnas
+        let data: Vec<Vec<usize>> = vec![vec![0, 2], vec![], vec![0, 1, 3]];
+        let rows = Incidence::from_rows(data.iter().map(Vec::as_slice));
+        assert_eq!(rows.len(), 3);
+        assert_eq!(rows.row(0), &[0, 2]);
+        assert!(rows.row(1).is_empty());
+        assert_eq!(rows.row(2), &[0, 1, 3]);
+
+        let columns = rows.transpose(4);
+        assert_eq!(columns.len(), 4);
+        assert_eq!(columns.row(0), &[0, 2]);
+        assert_eq!(columns.row(1), &[2]);
+        assert_eq!(columns.row(2), &[0]);
+        assert_eq!(columns.row_len(3), 1);
+        assert_eq!(columns.transpose(3), rows);
+    }
+
+    #[test]
+    fn test_from_rows_sorts_and_dedups() {
+        let data: Vec<Vec<usize>> = vec![vec![2, 0, 2], vec![], vec![3, 1, 0, 1]];
+        let rows = Incidence::from_rows(data.iter().map(Vec::as_slice));
+        assert_eq!(rows.len(), 3);
+        assert_eq!(rows.row(0), &[0, 2]);
+        assert!(rows.row(1).is_empty());
+        assert_eq!(rows.row(2), &[0, 1, 3]);
+        assert_eq!(rows.non_zero(), 5);
+    }
+}
@@ -0,0 +1,356 @@
+#[cfg(test)]
+mod test {
+    use std::error::Error;
+
+    use tempfile::tempdir;
+
+    use set_covering_problem::entity::set::{Set, SetError};
+    use set_covering_problem::utils::instance_format::{
+        read_csv, read_csv_costs, read_json, read_orlib, write_instance, write_instance_file, ConversionWarning, CsvOptions,
+        InstanceFormat
+    };
+    use set_covering_problem::utils::read_input::{InputError, ReadInput};
+
+    const ORLIB: &str = "4 3\n 2 1\n 3\n1\n 1\n2\n 1 2\n 1\n 3\n 2\n 2 3\n";
+
+    fn to_subsets(data : &[&[&str]]) -> Vec<Vec<String>> {
+        data.iter()
+            .map(|subset| subset.iter().map(|e| e.to_string()).collect())
+            .collect()
+    }
+
+    fn setup_weighted_set() -> Set {
+        let mut set = Set::new(to_subsets(&[&["a", "b"], &["b", "c"], &["c", "d"]]));
+        set.set_subset_costs(&[(0, 2.5), (2, 4.0)]).unwrap();
+        set.set_element_weights(&[("a".to_string(), 3.0)]).unwrap();
+        set.set_demands(&[("c".to_string(), 2)]).unwrap();
+        set
+    }
+
+    fn load(path : &str, format : &str) -> Set {
+        load_with(path, format, &[])
+    }
+
+    fn load_with(path : &str, format : &str, extra : &[&str]) -> Set {
+        let mut args = vec!["program".to_string(), "-c".to_string(), path.to_string(), "-from".to_string(), format.to_string()];
+        args.extend(extra.iter().map(|arg| arg.to_string()));
+        let mut read_input = ReadInput::new(args).unwrap();
+        let mut set = read_input.load_set().unwrap().build().unwrap();
+        set.set_demands(read_input.get_demands()).unwrap();
+        set.set_subset_costs(read_input.get_subset_costs()).unwrap();
+        set.set_element_weights(read_input.get_element_weights()).unwrap();
+        set.set_subset_names(read_input.get_subset_names()).unwrap();
+        set.set_subset_attributes(read_input.get_subset_attributes()).unwrap();
+        set.set_metadata(read_input.get_metadata().clone());
+        set
+    }
+
+    #[test]
+    fn test_read_orlib() {
+        let instance = read_orlib(ORLIB.as_bytes()).unwrap();
+        assert_eq!(instance.costs, vec![(0, 2.0), (1, 1.0), (2, 3.0)]);
+
+        let set = instance.builder.build().unwrap();
+        assert_eq!(set.get_size_set(), 4);
+        assert_eq!(set.get_size_subsets(), 3);
+        // Columna 1 cubre las filas 1 y 2, la 2 cubre 2 y 4, la 3 cubre 3 y 4
+        assert_eq!(set.elements_of(0), &[0, 1]);
+        assert_eq!(set.elements_of(1), &[1, 3]);
+        assert_eq!(set.elements_of(2), &[2, 3]);
+        assert_eq!(set.get_element_name(3).unwrap(), "4");
+    }
+
+    #[test]
+    fn test_read_orlib_errors() {
+        let error = |text : &str| match read_orlib(text.as_bytes()) {
+            Err(e) => e.to_string(),
+            other => panic!("se esperaba un error de formato: {:?}", other.map(|_| ())),
+        };
+
+        assert!(error("2 2\n1 x\n").starts_with("línea 2: se esperaba"));
+        assert!(error("2 2\n1 1\n1 3\n1 1\n").contains("línea 3: columna 3 fuera de rango"));
+        assert!(error("2 2\n1 1\n1 1\n").contains("fin de archivo inesperado"));
+        assert!(error("1 1\n1\n1 1\n9\n").contains("línea 4: dato sobrante '9'"));
+    }
+
+    #[test]
+    fn test_txt_round_trip_keeps_everything() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("instance.txt").to_str().unwrap().to_string();
+        let set = setup_weighted_set();
+
+        let warnings = write_instance_file(&set, InstanceFormat::Txt, &path).unwrap();
+        assert!(warnings.is_empty());
+        assert_eq!(load(&path, "txt"), set);
+    }
+
+    #[test]
+    fn test_orlib_conversion_warns_about_losses() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("instance.txt").to_str().unwrap().to_string();
+        let set = setup_weighted_set();
+
+        let warnings = write_instance_file(&set, InstanceFormat::OrLibrary, &path).unwrap();
+        let format = InstanceFormat::OrLibrary;
+        assert_eq!(warnings, vec![
+            ConversionWarning::ElementLabels { format },
+            ConversionWarning::ElementWeights { format, count : 1 },
+            ConversionWarning::Demands { format, count : 1 },
+            ConversionWarning::NonIntegerCosts { format, count : 1 },
+        ]);
+
+        // Estructura y costos se conservan
+        let converted = load(&path, "orlib");
+        for subset in 0..set.get_size_subsets() {
+            assert_eq!(converted.elements_of(subset), set.elements_of(subset));
+            assert_eq!(converted.get_subset_cost(subset), set.get_subset_cost(subset));
+        }
+        assert_eq!(converted.get_element_weight(0), 1.0);
+    }
+
+    #[test]
+    fn test_orlib_to_txt_is_lossless() {
+        let instance = read_orlib(ORLIB.as_bytes()).unwrap();
+        let mut set = instance.builder.build().unwrap();
+        set.set_subset_costs(&instance.costs).unwrap();
+
+        let mut orlib: Vec<u8> = Vec::new();
+        assert!(write_instance(&set, InstanceFormat::OrLibrary, &mut orlib).unwrap().is_empty());
+
+        let mut txt: Vec<u8> = Vec::new();
+        assert!(write_instance(&set, InstanceFormat::Txt, &mut txt).unwrap().is_empty());
+        let txt = String::from_utf8(txt).unwrap();
+        assert_eq!(txt.lines().next(), Some("#universe: 1,2,3,4"));
+        assert!(txt.contains("#cost: 0=2,2=3\n"));
+    }
+
+    #[test]
+    fn test_txt_rejects_unwritable_labels() {
+        let set = Set::new(to_subsets(&[&["a,b", "c"]]));
+        let mut out: Vec<u8> = Vec::new();
+        assert!(matches!(write_instance(&set, InstanceFormat::Txt, &mut out), Err(InputError::Validation { .. })));
+
+        // '=' solo estorba en las etiquetas que van en #demand o #weight
+        let mut set = Set::new(to_subsets(&[&["a=b", "c"]]));
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("copy.txt").to_str().unwrap().to_string();
+        assert!(write_instance_file(&set, InstanceFormat::Txt, &path).unwrap().is_empty());
+        assert_eq!(load(&path, "txt").get_elements(), vec!["a=b".to_string(), "c".to_string()]);
+
+        set.set_element_weights(&[("a=b".to_string(), 2.0)]).unwrap();
+        assert!(matches!(write_instance(&set, InstanceFormat::Txt, &mut out), Err(InputError::Validation { .. })));
+    }
+
+    const JSON: &str = r#"{
+        "metadata": { "origen": "rutas", "version": 3 },
+        "subsets": [
+            { "name": "route-1", "elements": ["a", "b"], "cost": 2.5, "zone": "norte", "capacity": 12 },
+            { "elements": ["b", "c"] },
+            { "name": "route-3", "elements": ["c", 7] }
+        ],
+        "demands": { "c": 2 },
+        "weights": { "a": 4 }
+    }"#;
+
+    #[test]
+    fn test_read_json() {
+        let instance = read_json(JSON.as_bytes()).unwrap();
+        assert_eq!(instance.universe, None);
+        assert_eq!(instance.costs, vec![(0, 2.5)]);
+        assert_eq!(instance.names, vec![(0, "route-1".to_string()), (2, "route-3".to_string())]);
+        assert_eq!(instance.attributes.len(), 1);
+        assert_eq!(instance.attributes[0].0, 0);
+        assert_eq!(instance.attributes[0].1["zone"], "norte");
+        assert_eq!(instance.attributes[0].1["capacity"], 12);
+        assert_eq!(instance.demands, vec![("c".to_string(), 2)]);
+        assert_eq!(instance.weights, vec![("a".to_string(), 4.0)]);
+        assert_eq!(instance.metadata["version"], 3);
+
+        let set = instance.builder.build().unwrap();
+        assert_eq!(set.get_size_set(), 4);
+        assert_eq!(set.get_element_name(3).unwrap(), "7");
+    }
+
+    #[test]
+    fn test_read_json_errors() {
+        let error = |text : &str| match read_json(text.as_bytes()) {
+            Err(e) => e.to_string(),
+            other => panic!("se esperaba un error de formato: {:?}", other.map(|_| ())),
+        };
+
+        assert!(error(r#"{ "subsets": [{ "elements": ["a"] }], "costs": {} }"#).contains("costs"));
+        assert!(error(r#"{ "subsets": [{ "elements": ["a"], "cost": -1 }] }"#).contains("costo inválido"));
+        assert!(error(r#"{ "subsets": [{ "elements": ["a"] }], "weights": { "a": -2 } }"#).contains("peso inválido -2 para el elemento a"));
+        assert!(error("{ \"subsets\": [\n  { \"elements\": [\"a\"] },\n  { \"elements\": [\"b\"], \"cost\": -1 }\n] }").starts_with("línea 3, columna "));
+        assert!(error("{ \"subsets\": [{ \"elements\": [\"a\"] }],\n  \"weights\": {\n    \"a\": -2 } }").starts_with("línea 3, columna "));
+        assert!(error("{ \"subsets\": [\n  { \"elements\": [\"a\", true] }\n] }").starts_with("línea 2, columna "));
+    }
+
+    #[test]
+    fn test_json_round_trip_keeps_names_and_metadata() {
+        let dir = tempdir().unwrap();
+        let source = dir.path().join("source.json");
+        std::fs::write(&source, JSON).unwrap();
+        let set = load(source.to_str().unwrap(), "json");
+        assert_eq!(set.get_subset_name(2), "route-3");
+        assert_eq!(set.get_demand(set.get_element_index("c").unwrap()), 2);
+
+        let path = dir.path().join("copy.json").to_str().unwrap().to_string();
+        assert!(write_instance_file(&set, InstanceFormat::Json, &path).unwrap().is_empty());
+        assert_eq!(load(&path, "json"), set);
+        assert_eq!(set.subset_attributes[0]["capacity"], 12);
+
+        // txt conserva todo salvo nombres, atributos y metadatos
+        let path = dir.path().join("copy.txt").to_str().unwrap().to_string();
+        let format = InstanceFormat::Txt;
+        assert_eq!(write_instance_file(&set, format, &path).unwrap(), vec![
+            ConversionWarning::SubsetNames { format, count : 2 },
+            ConversionWarning::SubsetAttributes { format, count : 1 },
+            ConversionWarning::Metadata { format, count : 2 },
+        ]);
+        let converted = load(&path, "txt");
+        assert!(!converted.has_subset_names());
+        assert_eq!(converted.subset_costs, set.subset_costs);
+        assert_eq!(converted.element_weights, set.element_weights);
+    }
+
+    #[test]
+    fn test_json_rejects_duplicate_subset_names() {
+        let instance = read_json(r#"{ "subsets": [
+            { "name": "r", "elements": ["a"] },
+            { "name": "r", "elements": ["b"] }
+        ] }"#.as_bytes()).unwrap();
+        let mut set = instance.builder.build().unwrap();
+        assert_eq!(
+            set.set_subset_names(&instance.names),
+            Err(SetError::DuplicateSubsetName { name : "r".to_string(), first : 0, second : 1 })
+        );
+    }
+
+    fn csv_error(text : &str, options : &CsvOptions) -> String {
+        match read_csv(text.as_bytes(), options) {
+            Err(e) => e.to_string(),
+            other => panic!("se esperaba un error de formato: {:?}", other.map(|_| ())),
+        }
+    }
+
+    #[test]
+    fn test_read_csv_groups_edges() {
+        // Las filas de r1 no están juntas y a se repite en r1
+        let text = "r1,a\nr2,b,2\n\nr1, b \nr1,a\nr3,c\nr2,c\n";
+        let instance = read_csv(text.as_bytes(), &CsvOptions::default()).unwrap();
+        assert_eq!(instance.names, vec![(0, "r1".to_string()), (1, "r2".to_string()), (2, "r3".to_string())]);
+        assert_eq!(instance.weights, vec![("b".to_string(), 2.0)]);
+
+        let set = instance.builder.build().unwrap();
+        assert_eq!(set.get_size_subsets(), 3);
+        assert_eq!(set.elements_of(0), &[0, 1]);
+        assert_eq!(set.elements_of(1), &[1, 2]);
+        assert_eq!(set.elements_of(2), &[2]);
+    }
+
+    #[test]
+    fn test_read_csv_dialects() {
+        let expected = vec!["a;b".to_string(), "c\"d".to_string()];
+        let elements = |text : &str, options : CsvOptions| -> Vec<String> {
+            let set = read_csv(text.as_bytes(), &options).unwrap().builder.build().unwrap();
+            set.elements_of(0).iter().map(|&element| set.get_element_name(element).unwrap().into_owned()).collect()
+        };
+
+        let semicolon = CsvOptions { delimiter : b';', header : true, ..CsvOptions::default() };
+        assert_eq!(elements("ruta;parada\nr1;\"a;b\"\nr1;\"c\"\"d\"\n", semicolon), expected);
+
+        let tab_single_quote = CsvOptions { delimiter : b'\t', quote : Some(b'\''), escape : Some(b'\\'), ..CsvOptions::default() };
+        assert_eq!(elements("r1\t'a;b'\nr1\t'c\"d'\n", tab_single_quote), expected);
+
+        let unquoted = CsvOptions { delimiter : b'|', quote : None, ..CsvOptions::default() };
+        assert_eq!(elements("r1|a;b\nr1|c\"d\n", unquoted), expected);
+    }
+
+    #[test]
+    fn test_read_csv_errors() {
+        let options = CsvOptions::default();
+        assert!(csv_error("r1,a\nr1,b,1,2\n", &options).starts_with("línea 2: se esperaban 2 o 3 campos"));
+        assert!(csv_error("r1,a\nr1\n", &options).contains("línea 2: se esperaban 2 o 3 campos (subconjunto, elemento[, peso]) y hay 1"));
+        assert!(csv_error("r1,a\n,b\n", &options)== "línea 2: campo vacío");
+        assert!(csv_error("r1,a,x\n", &options)== "línea 1: peso inválido 'x'");
+        assert!(csv_error("r1,a,1\nr2,a,2\n", &options)== "línea 2: el elemento a tiene pesos distintos (1 y 2)");
+        assert!(csv_error("r1,a\nr1,\"b\nr2,c\n", &options).contains("comilla sin cerrar"));
+
+        // El encabezado cuenta como línea 1
+        let header = CsvOptions { header : true, ..options };
+        assert!(csv_error("subset,element\nr1,a\n\nr1,\n", &header).starts_with("línea 4:"));
+        assert!(csv_error("\r\nr1,a\r\n\r\n\r\nr1,b,x\r\n", &options).starts_with("línea 5:"));
+        match read_csv(&b"r1,a\n\n\nr1,b\xffc\n"[..], &options) {
+            Err(e) => {
+                assert_eq!(e.location().and_then(|location| location.line), Some(4));
+                assert_eq!(e.location().and_then(|location| location.column), Some(2));
+                assert!(e.source().is_some());
+            }
+            other => panic!("se esperaba un error de formato: {:?}", other.map(|_| ())),
+        }
+    }
+
+    #[test]
+    fn test_read_csv_costs() {
+        let names = vec![(0, "r1".to_string()), (1, "r2".to_string())];
+        let options = CsvOptions { header : true, ..CsvOptions::default() };
+        let costs = read_csv_costs("subset,cost\nr2,4.5\n r1 , 2\n".as_bytes(), &options, &names).unwrap();
+        assert_eq!(costs, vec![(1, 4.5), (0, 2.0)]);
+
+        let error = |text : &str| match read_csv_costs(text.as_bytes(), &CsvOptions::default(), &names) {
+            Err(e) => e.to_string(),
+            other => panic!("se esperaba un error de formato: {:?}", other),
+        };
+        assert!(error("r1,1\nr9,2\n")== "línea 2: subconjunto desconocido 'r9'");
+        assert!(error("r1,-1\n")== "línea 1: costo inválido '-1'");
+        assert!(error("r1\n").contains("se esperaban 2 campos"));
+    }
+
+    #[test]
+    fn test_csv_round_trip() {
+        let dir = tempdir().unwrap();
+        let mut set = setup_weighted_set();
+        set.set_subset_names(&[(0, "ruta, norte".to_string())]).unwrap();
+
+        let path = dir.path().join("edges.csv").to_str().unwrap().to_string();
+        let format = InstanceFormat::Csv;
+        assert_eq!(write_instance_file(&set, format, &path).unwrap(), vec![
+            ConversionWarning::SubsetCosts { format, count : 2 },
+            ConversionWarning::Demands { format, count : 1 },
+        ]);
+        assert!(std::fs::read_to_string(&path).unwrap().starts_with("subset,element,weight\n\"ruta, norte\",a,3\n"));
+
+        let costs = dir.path().join("costs.csv");
+        std::fs::write(&costs, "subset,cost\n\"ruta, norte\",2.5\nS2,4\n").unwrap();
+        let converted = load_with(&path, "csv", &["-header", "-costs", costs.to_str().unwrap()]);
+        assert_eq!(converted.get_subset_name(0), "ruta, norte");
+        assert_eq!(converted.get_subset_name(1), "S1");
+        assert_eq!(converted.subset_costs, set.subset_costs);
+        assert_eq!(converted.element_weights, set.element_weights);
+        for subset in 0..set.get_size_subsets() {
+            assert_eq!(converted.elements_of(subset), set.elements_of(subset));
+        }
+
+        // Sin -header el encabezado escrito se reconoce y no se lee como un subconjunto
+        let converted = load_with(&path, "csv", &["-costs", costs.to_str().unwrap()]);
+        assert_eq!(converted.get_size_subsets(), set.get_size_subsets());
+        assert_eq!(converted.get_subset_index("subset"), None);
+        assert_eq!(converted.get_element_index("element"), None);
+        assert_eq!(converted.subset_costs, set.subset_costs);
+    }
+
+    #[test]
+    fn test_read_csv_detects_written_header() {
+        let options = CsvOptions::default();
+        let instance = read_csv("Subset,Element\nr1,a\n".as_bytes(), &options).unwrap();
+        assert_eq!(instance.names, vec![(0, "r1".to_string())]);
+
+        // Solo en la primera fila; más adelante es un subconjunto como cualquier otro
+        let instance = read_csv("r1,a\nsubset,element\n".as_bytes(), &options).unwrap();
+        assert_eq!(instance.names.len(), 2);
+
+        let names = vec![(0, "r1".to_string())];
+        assert_eq!(read_csv_costs("subset,cost\nr1,2\n".as_bytes(), &options, &names).unwrap(), vec![(0, 2.0)]);
+    }
+}
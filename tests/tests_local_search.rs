@@ -0,0 +1,149 @@
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+
+    use set_covering_problem::entity::cover_mode::CoverMode;
+    use set_covering_problem::entity::objective::WeightedObjective;
+    use set_covering_problem::entity::set::Set;
+    use set_covering_problem::entity::subset_cover::SubsetCover;
+    use set_covering_problem::solver::local_search::{LocalSearch, Strategy};
+    use set_covering_problem::solver::observer::{CancelObserver, TraceObserver};
+    use set_covering_problem::solver::registry::SolverRegistry;
+    use set_covering_problem::solver::termination::{StopReason, Termination};
+    use set_covering_problem::solver::{NoopObserver, SolverConfig};
+
+    fn to_subsets(data : &[&[&str]]) -> Vec<Vec<String>> {
+        data.iter()
+            .map(|subset| subset.iter().map(|e| e.to_string()).collect())
+            .collect()
+    }
+
+    fn weighted_set(data : &[&[&str]], costs : &[f64]) -> Set {
+        let mut set = Set::new(to_subsets(data));
+        let costs: Vec<(usize, f64)> = costs.iter().copied().enumerate().collect();
+        set.set_subset_costs(&costs).unwrap();
+        set
+    }
+
+    fn weighted_cover(subsets : &[usize], set : &Set) -> SubsetCover {
+        let mut cover = SubsetCover::with_objective(set, CoverMode::Full, Arc::new(WeightedObjective));
+        cover.set_subsets(subsets.to_vec(), set).unwrap();
+        cover
+    }
+
+    #[test]
+    fn test_removes_redundant_subsets() {
+        let set = Set::new(to_subsets(&[
+            &["a", "b"],             // S0
+            &["c", "d"],             // S1
+            &["e", "f"],             // S2
+            &["a", "b", "c"],        // S3
+            &["d", "e", "f"],        // S4
+            &["b", "d"],             // S5
+        ]));
+        let mut cover = SubsetCover::with_mode(&set, CoverMode::Full);
+        cover.set_subsets(vec![0, 3, 4, 5], &set).unwrap();
+
+        let (polished, stats) = LocalSearch::default().polish(&cover, &set, &Termination::none(), &mut NoopObserver);
+        assert_eq!(polished.elements, vec![3, 4]);
+        assert_eq!(stats.removals, 2);
+        assert!(stats.final_cost < stats.initial_cost);
+    }
+
+    #[test]
+    fn test_swaps_keep_coverage() {
+        // S0 y S1 cubren lo mismo pero S1 es más barato
+        let set = weighted_set(&[&["a", "b", "c"], &["a", "b", "c"], &["c", "d"]], &[3.0, 1.0, 1.0]);
+        let (polished, stats) = LocalSearch::default().polish(&weighted_cover(&[0, 2], &set), &set, &Termination::none(), &mut NoopObserver);
+        assert_eq!(polished.elements, vec![1, 2]);
+        assert_eq!(stats.swaps, 1);
+
+        // S2 reemplaza a S0 y S1 juntos
+        let set = weighted_set(&[&["a", "b"], &["c", "d"], &["a", "b", "c", "d"], &["e"]], &[1.0, 1.0, 1.5, 1.0]);
+        let (polished, stats) = LocalSearch::default().polish(&weighted_cover(&[0, 1, 3], &set), &set, &Termination::none(), &mut NoopObserver);
+        assert_eq!(polished.elements, vec![2, 3]);
+        assert_eq!(stats.double_swaps, 1);
+        assert!(set.is_cover_valid(&polished.elements));
+    }
+
+    #[test]
+    fn test_first_and_best_improvement() {
+        let set = weighted_set(&[&["a"], &["a", "b"], &["b"]], &[1.0, 5.0, 1.0]);
+        let cover = weighted_cover(&[0, 1, 2], &set);
+
+        // Primera mejora quita S0 y luego S2; mejor mejora quita directamente el caro S1
+        let (first, _) = LocalSearch::new(Strategy::FirstImprovement).polish(&cover, &set, &Termination::none(), &mut NoopObserver);
+        let (best, _) = LocalSearch::new(Strategy::BestImprovement).polish(&cover, &set, &Termination::none(), &mut NoopObserver);
+        assert_eq!(first.elements, vec![1]);
+        assert_eq!(best.elements, vec![0, 2]);
+
+        assert_eq!(Strategy::from_name("best"), Some(Strategy::BestImprovement));
+        assert_eq!(Strategy::from_name("worst"), None);
+    }
+
+    #[test]
+    fn test_polish_after_solver_and_local_solver() {
+        let set = weighted_set(&[&["a", "b"], &["c", "d"], &["a", "b", "c", "d"], &["e"], &["d", "e"]], &[1.0, 1.0, 1.5, 1.0, 3.0]);
+        let config = SolverConfig::new(CoverMode::Full, Arc::new(WeightedObjective));
+        let registry = SolverRegistry::default();
+
+        let mut rng = StdRng::seed_from_u64(1);
+        let result = registry.create("greedy").unwrap().solve(&set, &config, &mut rng, &mut NoopObserver);
+        let (polished, _) = LocalSearch::new(Strategy::BestImprovement).polish(&result.cover, &set, &Termination::none(), &mut NoopObserver);
+        assert!(polished.cost <= result.cover.cost);
+        assert!(polished.is_feasible(&set));
+
+        // El solver local pule la solución inicial
+        let config = config.with_initial(&[0, 1, 3], &set).unwrap();
+        let result = registry.create("local").unwrap().solve(&set, &config, &mut rng, &mut NoopObserver);
+        assert_eq!(result.cover.elements, vec![2, 3]);
+        assert_eq!(result.stats.iterations, 1);
+    }
+
+    #[test]
+    fn test_polish_honors_termination_and_cancel() {
+        let set = Set::new(to_subsets(&[
+            &["a", "b"],             // S0
+            &["c", "d"],             // S1
+            &["e", "f"],             // S2
+            &["a", "b", "c"],        // S3
+            &["d", "e", "f"],        // S4
+            &["b", "d"],             // S5
+        ]));
+        let mut cover = SubsetCover::with_mode(&set, CoverMode::Full);
+        cover.set_subsets(vec![0, 3, 4, 5], &set).unwrap();
+        let search = LocalSearch::default();
+
+        // Cada movimiento aceptado se reporta como una iteración con mejora
+        let mut trace = TraceObserver::new();
+        let (polished, stats) = search.polish(&cover, &set, &Termination::none(), &mut trace);
+        assert_eq!(stats.stop_reason, StopReason::Completed);
+        assert_eq!(trace.points.len(), 2);
+        assert_eq!(trace.improvements.last().unwrap().cost, polished.cost);
+
+        let (polished, stats) = search.polish(&cover, &set, &Termination::none().with_max_iterations(1), &mut NoopObserver);
+        assert_eq!(stats.stop_reason, StopReason::MaxIterations);
+        assert_eq!(stats.moves(), 1);
+        assert_eq!(polished.size, 3);
+
+        let (_, stats) = search.polish(&cover, &set, &Termination::none().with_target_cost(cover.cost), &mut NoopObserver);
+        assert_eq!(stats.stop_reason, StopReason::TargetCost);
+        assert_eq!(stats.moves(), 0);
+
+        let cancel = CancelObserver::new();
+        cancel.cancel();
+        let (polished, stats) = search.polish(&cover, &set, &Termination::none(), &mut cancel.clone());
+        assert_eq!(stats.stop_reason, StopReason::Cancelled);
+        assert_eq!(polished.elements, cover.elements);
+
+        // El solver local reporta el criterio con el que se detuvo el pulido
+        let config = SolverConfig { termination : Termination::none().with_max_iterations(1), ..SolverConfig::new(CoverMode::Full, Arc::new(WeightedObjective)) };
+        let config = config.with_initial(&[0, 3, 4, 5], &set).unwrap();
+        let result = SolverRegistry::default().create("local").unwrap().solve(&set, &config, &mut StdRng::seed_from_u64(1), &mut NoopObserver);
+        assert_eq!(result.stats.stop_reason, StopReason::MaxIterations);
+        assert_eq!(result.stats.iterations, 1);
+    }
+}
@@ -0,0 +1,78 @@
+#[cfg(test)]
+mod test {
+    use set_covering_problem::entity::cover_mode::CoverMode;
+    use set_covering_problem::entity::max_coverage::{Budget, MaxCoverage};
+    use set_covering_problem::entity::set::Set;
+    use set_covering_problem::entity::subset_cover::SubsetCover;
+
+    fn to_subsets(data : &[&[&str]]) -> Vec<Vec<String>> {
+        data.iter()
+            .map(|subset| subset.iter().map(|e| e.to_string()).collect())
+            .collect()
+    }
+
+    // U = {a, b, c, d, e, f, g}
+    fn setup_test_set() -> Set {
+        Set::new(to_subsets(&[
+            &["a", "b", "c", "d"],   // S0
+            &["a", "b", "e"],        // S1
+            &["c", "d", "f"],        // S2
+            &["e", "f", "g"],        // S3
+            &["g"],                  // S4
+        ]))
+    }
+
+    #[test]
+    fn test_greedy_cardinality_budget() {
+        let set = setup_test_set();
+        let max_coverage = MaxCoverage::new(Budget::Subsets(2));
+
+        // S0 cubre 4; después S3 agrega {e, f, g}
+        let selected = max_coverage.greedy(&set);
+        assert_eq!(selected, vec![0, 3]);
+        assert_eq!(max_coverage.covered_weight(&selected, &set), 7.0);
+    }
+
+    #[test]
+    fn test_greedy_cost_budget() {
+        let mut set = setup_test_set();
+        set.set_subset_costs(&[(0, 4.0), (1, 1.0), (2, 1.0), (3, 3.0), (4, 0.5)]).unwrap();
+        let max_coverage = MaxCoverage::new(Budget::Cost(2.5));
+
+        // Por razón ganancia/costo: S1 (3), S2 (3), S4 (2) con costo 2.5
+        let selected = max_coverage.greedy(&set);
+        assert_eq!(selected, vec![1, 2, 4]);
+        assert!(Budget::Cost(2.5).is_within(&selected, &set));
+        assert_eq!(max_coverage.covered_weight(&selected, &set), 7.0);
+    }
+
+    #[test]
+    fn test_greedy_with_element_weights() {
+        let mut set = setup_test_set();
+        set.set_element_weights(&[("g".to_string(), 10.0)]).unwrap();
+        let max_coverage = MaxCoverage::new(Budget::Subsets(1));
+
+        // S3 cubre g, que pesa más que los 4 elementos de S0
+        assert_eq!(max_coverage.greedy(&set), vec![3]);
+    }
+
+    #[test]
+    fn test_max_coverage_mode() {
+        let set = setup_test_set();
+        let mode = CoverMode::MaxCoverage(Budget::Subsets(2));
+
+        assert!(mode.is_feasible(&[0, 3], &set));
+        assert!(!mode.is_feasible(&[0, 1, 3], &set), "Tres subconjuntos exceden el presupuesto");
+
+        // Sin peso sin cubrir: costo = 2 / 5 = 0.4
+        assert_eq!(SubsetCover::calculate_cost_mode(&[0, 3], &set, &mode), 0.4);
+        assert!(
+            SubsetCover::calculate_cost_mode(&[0, 1, 3], &set, &mode) > SubsetCover::calculate_cost_mode(&[], &set, &mode),
+            "Violar el presupuesto debe ser peor que no cubrir nada"
+        );
+
+        let repaired = mode.repair(&[0, 1, 2, 4], &set);
+        assert_eq!(repaired.len(), 2);
+        assert!(mode.is_feasible(&repaired, &set));
+    }
+}
@@ -0,0 +1,121 @@
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use set_covering_problem::entity::cover_mode::CoverMode;
+    use set_covering_problem::entity::objective::{
+        objective_from_name, AdaptivePenaltyObjective, CardinalityObjective, DefaultObjective,
+        LexicographicObjective, Objective, SquaredShortfallObjective, WeightedObjective, OBJECTIVE_NAMES
+    };
+    use set_covering_problem::entity::set::Set;
+    use set_covering_problem::entity::stats::CoverReport;
+    use set_covering_problem::entity::subset_cover::SubsetCover;
+
+    // |X| = 5, |S| = 5, max(S) = 5
+    fn setup_test_set() -> Set {
+        let subsets_data = vec![
+            vec!["a".to_string(), "b".to_string()],                     // S0
+            vec!["c".to_string(), "d".to_string()],                     // S1
+            vec!["e".to_string()],                                      // S2
+            vec!["a".to_string(), "c".to_string()],                     // S3
+            vec!["a".to_string(), "b".to_string(), "c".to_string(), "d".to_string(), "e".to_string()], // S4
+        ];
+        Set::new(subsets_data)
+    }
+
+    #[test]
+    fn test_default_matches_calculate_cost() {
+        let set = setup_test_set();
+        for subsets in [vec![], vec![0, 1], vec![4], vec![0, 1, 2, 4]] {
+            assert_eq!(
+                DefaultObjective.cost(&subsets, &set, &CoverMode::Full),
+                SubsetCover::calculate_cost(&subsets, &set)
+            );
+        }
+    }
+
+    #[test]
+    fn test_builtin_objectives() {
+        let mut set = setup_test_set();
+        set.set_subset_costs(&[(4, 6.0)]).unwrap();
+        let mode = CoverMode::Full;
+
+        // C = {S0, S1}: falta e
+        assert_eq!(CardinalityObjective.cost(&[0, 1], &set, &mode), 2.0);
+        // (costo(C) + faltante * costo(S)) / |S| = (2 + 1 * 10) / 5 = 2.4
+        assert_eq!(WeightedObjective.cost(&[0, 1], &set, &mode), 2.4);
+        // (2 + 1² * 5 * 5) / 5 = 5.4 y con faltante 3: (1 + 9 * 25) / 5 = 45.2
+        assert_eq!(SquaredShortfallObjective.cost(&[0, 1], &set, &mode), 5.4);
+        assert_eq!(SquaredShortfallObjective.cost(&[0], &set, &mode), 45.2);
+        // faltante * (costo total + 1) + costo(C) = 1 * 11 + 2 = 13
+        assert_eq!(LexicographicObjective.cost(&[0, 1], &set, &mode), 13.0);
+        assert!(
+            LexicographicObjective.cost(&[0, 1, 2, 3, 4], &set, &mode) < LexicographicObjective.cost(&[0, 1], &set, &mode),
+            "Menos faltante siempre gana sin importar el costo"
+        );
+        // Con el mismo faltante decide el costo, no |C|
+        assert!(LexicographicObjective.cost(&[0, 1, 2], &set, &mode) < LexicographicObjective.cost(&[4], &set, &mode));
+    }
+
+    #[test]
+    fn test_lexicographic_with_fractional_weights() {
+        // |S| = 10: S0 cubre todo salvo h (peso 0.5), que solo está en S9
+        let mut subsets: Vec<Vec<String>> = vec![(1..=8).map(|element| element.to_string()).collect()];
+        subsets.extend((1..=8).map(|element| vec![element.to_string()]));
+        subsets.push(vec!["h".to_string()]);
+        let mut set = Set::new(subsets);
+        set.set_element_weights(&[("h".to_string(), 0.5)]).unwrap();
+        let mode = CoverMode::Full;
+        let objective = LexicographicObjective;
+
+        // Una cobertura completa de 10 subconjuntos le gana a un solo subconjunto con faltante 0.5
+        let full: Vec<usize> = (0..10).collect();
+        assert!(objective.cost(&full, &set, &mode) < objective.cost(&[0], &set, &mode));
+
+        // Faltantes 0.3 y 0.2 difieren en 0.1, menos que cualquier peso, y aun así deciden
+        let mut set = Set::new(vec![vec!["a".to_string()], vec!["b".to_string()], vec!["a".to_string(), "b".to_string()]]);
+        set.set_element_weights(&[("a".to_string(), 0.3), ("b".to_string(), 0.2)]).unwrap();
+        set.set_subset_costs(&[(0, 1.0), (1, 40.0), (2, 100.0)]).unwrap();
+        assert!(objective.cost(&[0], &set, &mode) < objective.cost(&[1], &set, &mode));
+        set.set_subset_costs(&[(0, 40.0), (1, 1.0)]).unwrap();
+        assert!(objective.cost(&[1], &set, &mode) > objective.cost(&[0], &set, &mode));
+    }
+
+    #[test]
+    fn test_adaptive_penalty_updates_factor() {
+        let set = setup_test_set();
+        let objective = AdaptivePenaltyObjective::new(1.0, 2.0, 0.5, 8.0);
+
+        // (2 + 1 * 1) / 5 = 0.6
+        assert_eq!(objective.cost(&[0, 1], &set, &CoverMode::Full), 0.6);
+
+        objective.update(false);
+        objective.update(false);
+        assert_eq!(objective.get_factor(), 4.0);
+        // (2 + 1 * 4) / 5 = 1.2
+        assert_eq!(objective.cost(&[0, 1], &set, &CoverMode::Full), 1.2);
+
+        for _ in 0..10 {
+            objective.update(true);
+        }
+        assert_eq!(objective.get_factor(), 0.5, "El factor se acota por abajo");
+    }
+
+    #[test]
+    fn test_cover_records_objective() {
+        let set = setup_test_set();
+        for name in OBJECTIVE_NAMES {
+            assert_eq!(objective_from_name(name).unwrap().name(), name);
+        }
+        assert!(objective_from_name("unknown").is_none());
+
+        let mut cover = SubsetCover::with_objective(&set, CoverMode::Full, Arc::new(CardinalityObjective));
+        cover.add_subset(4, &set).unwrap();
+        assert_eq!(cover.cost, 1.0);
+
+        let report = CoverReport::from_cover(&cover, &set);
+        assert_eq!(report.objective.as_deref(), Some("cardinality"));
+        assert_eq!(report.objective_cost, Some(1.0));
+        assert!(report.to_string().contains("cardinality"));
+    }
+}
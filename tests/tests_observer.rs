@@ -0,0 +1,116 @@
+#[cfg(test)]
+mod test {
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+
+    use set_covering_problem::entity::set::Set;
+    use set_covering_problem::entity::subset_cover::SubsetCover;
+    use set_covering_problem::solver::observer::{CancelObserver, ObserverGroup, TraceObserver};
+    use set_covering_problem::solver::registry::SolverRegistry;
+    use set_covering_problem::solver::termination::{StopReason, Termination};
+    use set_covering_problem::solver::{SolverConfig, SolverObserver, SolverResult, SolverStats};
+
+    fn to_subsets(data : &[&[&str]]) -> Vec<Vec<String>> {
+        data.iter()
+            .map(|subset| subset.iter().map(|e| e.to_string()).collect())
+            .collect()
+    }
+
+    fn setup_test_set() -> Set {
+        Set::new(to_subsets(&[
+            &["a", "b"],             // S0
+            &["c", "d"],             // S1
+            &["e", "f"],             // S2
+            &["a", "b", "c"],        // S3
+            &["d", "e", "f"],        // S4
+            &["b", "d"],             // S5
+        ]))
+    }
+
+    // Cancela la búsqueda al llegar a cierta iteración y cuenta los cierres
+    struct StopAt {
+        iteration : usize,
+        seen : usize,
+        finished : usize
+    }
+
+    impl SolverObserver for StopAt {
+        fn on_iteration(&mut self, _iteration : usize, _best : &SubsetCover) {
+            self.seen += 1;
+        }
+
+        fn on_finish(&mut self, _stats : &SolverStats) {
+            self.finished += 1;
+        }
+
+        fn should_stop(&self) -> bool {
+            self.seen >= self.iteration
+        }
+    }
+
+    fn run(name : &str, config : &SolverConfig, observer : &mut dyn SolverObserver) -> SolverResult {
+        let mut solver = SolverRegistry::default().create(name).unwrap();
+        let mut rng = StdRng::seed_from_u64(5);
+        solver.solve(&setup_test_set(), config, &mut rng, observer)
+    }
+
+    #[test]
+    fn test_cancel_flag_stops_before_searching() {
+        let cancel = CancelObserver::new();
+        let handle = cancel.clone();
+        handle.cancel();
+        assert!(cancel.is_cancelled());
+
+        for name in ["greedy", "rfd"] {
+            let result = run(name, &SolverConfig::default(), &mut cancel.clone());
+            assert_eq!(result.stats.stop_reason, StopReason::Cancelled);
+            assert_eq!(result.stats.iterations, 0);
+            assert!(result.cover.elements.is_empty());
+        }
+    }
+
+    #[test]
+    fn test_group_forwards_events_and_stops() {
+        let config = SolverConfig { termination : Termination::none().with_max_iterations(50), drops : 3, ..SolverConfig::default() };
+        let mut stop = StopAt { iteration : 7, seen : 0, finished : 0 };
+        let mut trace = TraceObserver::new();
+
+        let result = {
+            let mut group = ObserverGroup::new();
+            group.push(&mut stop);
+            group.push(&mut trace);
+            run("rfd", &config, &mut group)
+        };
+
+        assert_eq!(result.stats.stop_reason, StopReason::Cancelled);
+        assert_eq!(result.stats.iterations, 7);
+        assert_eq!(stop.finished, 1);
+        assert_eq!(trace.points.len(), 7);
+        assert_eq!(trace.improvements.first().map(|point| point.iteration), Some(0));
+        assert_eq!(trace.points.last().unwrap().cost, result.cover.cost);
+    }
+
+    #[test]
+    fn test_restarts_are_reported() {
+        let config = SolverConfig { termination : Termination::none().with_max_iterations(30), drops : 2, restart_after : 5, ..SolverConfig::default() };
+        let mut trace = TraceObserver::new();
+        let result = run("rfd", &config, &mut trace);
+
+        assert!(result.stats.restarts > 0);
+        assert_eq!(trace.restarts.len(), result.stats.restarts);
+        assert!(trace.restarts.windows(2).all(|pair| pair[1] - pair[0] >= 5));
+    }
+
+    #[test]
+    fn test_trace_svg() {
+        let mut trace = TraceObserver::new();
+        let config = SolverConfig { termination : Termination::none().with_max_iterations(10), drops : 2, restart_after : 3, ..SolverConfig::default() };
+        run("rfd", &config, &mut trace);
+
+        let svg = trace.to_svg().to_string();
+        assert!(svg.contains("<polyline"));
+        assert!(svg.contains("stroke-dasharray"));
+        assert_eq!(svg.matches("<text").count(), 4);
+        assert!(trace.to_json().contains("\"restarts\""));
+    }
+}
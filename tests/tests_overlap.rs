@@ -0,0 +1,59 @@
+#[cfg(test)]
+mod test {
+    use set_covering_problem::entity::overlap::OverlapAnalysis;
+    use set_covering_problem::entity::set::Set;
+
+    fn to_subsets(data : &[&[&str]]) -> Vec<Vec<String>> {
+        data.iter()
+            .map(|subset| subset.iter().map(|e| e.to_string()).collect())
+            .collect()
+    }
+
+    // Universal Set U = {a, b, c, d, e}
+    fn setup_test_set() -> Set {
+        Set::new(to_subsets(&[
+            &["a", "b", "c"],   // S0
+            &["c", "d"],        // S1
+            &["e"],             // S2
+            &["a", "b", "d"],   // S3
+        ]))
+    }
+
+    #[test]
+    fn test_overlap_matrix_and_multiplicity() {
+        let set = setup_test_set();
+        let analysis = OverlapAnalysis::new(&[0, 1, 2, 3], &set).unwrap();
+
+        assert_eq!(analysis.overlap(0, 0), 3);
+        assert_eq!(analysis.overlap(0, 3), 2);
+        assert_eq!(analysis.overlap(3, 0), 2);
+        assert_eq!(analysis.overlap(1, 2), 0);
+        assert_eq!(analysis.multiplicity, vec![2, 2, 2, 2, 1]);
+        // 9 coberturas sobre 5 elementos
+        assert_eq!(analysis.redundancy, 4);
+    }
+
+    #[test]
+    fn test_pairs_and_disjoint_subsets() {
+        let set = setup_test_set();
+        let analysis = set.overlap_analysis(&[3, 0, 1, 2]).unwrap();
+
+        assert_eq!(analysis.overlapping_pairs(), vec![(3, 0, 2), (0, 1, 1), (3, 1, 1)]);
+        assert_eq!(analysis.top_pairs(1), vec![(3, 0, 2)]);
+        assert_eq!(analysis.disjoint_subsets(), vec![2]);
+        assert_eq!(set.count_disjoint_subsets(&[3, 0, 1, 2]), 1);
+        assert!(set.overlap_analysis(&[0, 4]).is_err());
+    }
+
+    #[test]
+    fn test_bitsets_span_several_words() {
+        // 130 elementos: los bitsets ocupan tres palabras de 64 bits
+        let subsets: Vec<Vec<usize>> = vec![(0..130).collect(), (64..130).step_by(2).collect(), vec![129]];
+        let set = Set::from_indices(130, subsets).unwrap();
+        let analysis = set.overlap_analysis(&[0, 1, 2]).unwrap();
+
+        assert_eq!(analysis.overlap(0, 1), 33);
+        assert_eq!(analysis.overlap(1, 2), 0);
+        assert_eq!(analysis.overlap(0, 2), 1);
+    }
+}
@@ -1,8 +1,10 @@
 #[cfg(test)]
 mod tests {
-    use set_covering_problem::utils::read_input::{InputError,ReadInput};
+    use set_covering_problem::utils::read_input::{InputError,InputWarning,ReadInput};
+    use std::error::Error;
     use std::fs::File;
     use std::io::Write;
+    use std::path::Path;
     use tempfile::tempdir;
 
     #[test]
@@ -71,6 +73,14 @@ mod tests {
         assert_eq!(seeds.len(), 10);
     }
 
+    #[test]
+    fn test_missing_seed_value() {
+        for flag in ["-s", "-rs"] {
+            let mut ri = ReadInput::new(vec!["program".to_string(), flag.to_string()]).unwrap();
+            assert!(matches!(ri.get_seed(), Err(InputError::InvalidArgument { flag : found, .. }) if found == flag));
+        }
+    }
+
     #[test]
     fn test_get_help() {
         let args = vec!["program".to_string(), "-h".to_string()];
@@ -95,18 +105,19 @@ mod tests {
         let mut args = vec!["program".to_string(), "-c".to_string(), "nonexistent.txt".to_string()];
         let mut ri = ReadInput::new(args.clone()).unwrap();
         let res = ri.read_file_subset();
-        assert!(matches!(res, Err(InputError::InvalidPath(_))));
+        assert!(matches!(&res, Err(InputError::FileNotFound { path, .. }) if path.to_str() == Some("nonexistent.txt")));
+        assert!(res.unwrap_err().source().is_some());
 
         args.pop();
         ri = ReadInput::new(args.clone()).unwrap();
         let res = ri.read_file_subset();
-        assert!(matches!(res,Err(InputError::FileNotFound(_))));
+        assert!(matches!(res, Err(InputError::InvalidPath { .. })));
 
 
         args.push("fileNotType.s".to_string());
         ri = ReadInput::new(args.clone()).unwrap();
         let res = ri.read_file_subset();
-        assert!(matches!(res, Err(InputError::InvalidFormat(_))));
+        assert!(matches!(res, Err(InputError::InvalidPath { path, .. }) if path == "fileNotType.s"));
     }
 
     #[test]
@@ -122,4 +133,464 @@ mod tests {
         let ri = ReadInput::new(args).unwrap();
         assert!(ri.get_svg());
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_get_stats() {
+        use set_covering_problem::entity::stats::StatsFormat;
+
+        let ri = ReadInput::new(vec!["program".to_string(), "-v".to_string()]).unwrap();
+        assert!(matches!(ri.get_stats(), Ok(None)));
+
+        let ri = ReadInput::new(vec!["program".to_string(), "-stats".to_string(), "-v".to_string()]).unwrap();
+        assert!(matches!(ri.get_stats(), Ok(Some(StatsFormat::Table))));
+
+        let ri = ReadInput::new(vec!["program".to_string(), "-stats".to_string(), "json".to_string()]).unwrap();
+        assert!(matches!(ri.get_stats(), Ok(Some(StatsFormat::Json))));
+
+        let ri = ReadInput::new(vec!["program".to_string(), "-stats".to_string(), "xml".to_string()]).unwrap();
+        assert!(matches!(ri.get_stats(), Err(InputError::InvalidArgument { .. })));
+    }
+
+    fn write_file(dir : &tempfile::TempDir, lines : &[&str]) -> String {
+        let file_path = dir.path().join("test.txt");
+        let mut file = File::create(&file_path).unwrap();
+        for line in lines {
+            writeln!(file, "{}", line).unwrap();
+        }
+        file_path.to_str().unwrap().to_string()
+    }
+
+    #[test]
+    fn test_read_file_warnings() {
+        let dir = tempdir().unwrap();
+        let path = write_file(&dir, &["1,2", "", "3, 4", "2,1", "   "]);
+
+        let mut ri = ReadInput::new(vec!["program".to_string(), "-c".to_string(), path]).unwrap();
+        let subsets = ri.read_file_subset().unwrap();
+        assert_eq!(subsets.len(), 3, "Las líneas vacías no generan subconjuntos");
+        assert_eq!(ri.get_subset_line(1), Some(3));
+        assert_eq!(ri.get_warnings(), &[
+            InputWarning::BlankLine { line: 2 },
+            InputWarning::DuplicateSubset { line: 4, first_line: 1 },
+            InputWarning::BlankLine { line: 5 },
+        ]);
+    }
+
+    #[test]
+    fn test_duplicate_subsets_compare_elements() {
+        // Los ids numéricos pasan a ser de texto a mitad del archivo sin cambiar los ya vistos
+        let dir = tempdir().unwrap();
+        let path = write_file(&dir, &["1,2", "12", "a,1", "2,1,2", "1,a"]);
+
+        let mut ri = ReadInput::new(vec!["program".to_string(), "-c".to_string(), path]).unwrap();
+        ri.read_file_subset().unwrap();
+        assert_eq!(ri.get_warnings(), &[
+            InputWarning::DuplicateSubset { line: 4, first_line: 1 },
+            InputWarning::DuplicateSubset { line: 5, first_line: 3 },
+        ]);
+    }
+
+    #[test]
+    fn test_read_file_subset_rejects_other_formats() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("edges.csv");
+        std::fs::write(&path, "r1,a\nr2,b\n").unwrap();
+        let args = vec!["program".to_string(), "-c".to_string(), path.to_str().unwrap().to_string(), "-from".to_string(), "csv".to_string()];
+        let mut ri = ReadInput::new(args).unwrap();
+        assert!(matches!(ri.read_file_subset(), Err(InputError::InvalidArgument { flag, .. }) if flag == "-from"));
+        assert_eq!(ri.load_set().unwrap().build().unwrap().get_size_subsets(), 2);
+    }
+
+    #[test]
+    fn test_read_file_errors() {
+        let dir = tempdir().unwrap();
+        let path = write_file(&dir, &["1,2", "3,,4"]);
+        let mut ri = ReadInput::new(vec!["program".to_string(), "-c".to_string(), path.clone()]).unwrap();
+        let error = ri.read_file_subset().unwrap_err();
+        assert!(matches!(&error, InputError::EmptyElement { location }
+            if location.line == Some(2) && location.column == Some(3) && location.path.as_deref() == Some(Path::new(&path))));
+        assert_eq!(error.to_string(), format!("{}, línea 2, columna 3: Elemento vacío", path));
+
+        let dir = tempdir().unwrap();
+        let path = write_file(&dir, &["", ""]);
+        let mut ri = ReadInput::new(vec!["program".to_string(), "-c".to_string(), path]).unwrap();
+        assert!(matches!(ri.read_file_subset(), Err(InputError::EmptyFile { .. })));
+    }
+
+    #[test]
+    fn test_read_file_universe_header() {
+        let dir = tempdir().unwrap();
+        let path = write_file(&dir, &["#universe: a, b,c,d", "a,b", "c"]);
+        let mut ri = ReadInput::new(vec!["program".to_string(), "-c".to_string(), path]).unwrap();
+        let subsets = ri.read_file_subset().unwrap();
+
+        assert_eq!(subsets.len(), 2);
+        assert_eq!(ri.get_subset_line(0), Some(2));
+        assert_eq!(ri.universe_line, Some(1));
+        assert_eq!(ri.get_universe(), Some(&vec!["a".to_string(), "b".to_string(), "c".to_string(), "d".to_string()]));
+    }
+
+    #[test]
+    fn test_read_file_invalid_header() {
+        let dir = tempdir().unwrap();
+        let path = write_file(&dir, &["a,b", "#universe: a,b"]);
+        let mut ri = ReadInput::new(vec!["program".to_string(), "-c".to_string(), path]).unwrap();
+        assert!(matches!(ri.read_file_subset(), Err(InputError::InvalidHeader { location, .. }) if location.line == Some(2)));
+
+        let dir = tempdir().unwrap();
+        let path = write_file(&dir, &["#colors: a,b", "a,b"]);
+        let mut ri = ReadInput::new(vec!["program".to_string(), "-c".to_string(), path]).unwrap();
+        assert!(matches!(ri.read_file_subset(), Err(InputError::InvalidHeader { location, .. }) if location.line == Some(1)));
+
+        let dir = tempdir().unwrap();
+        let path = write_file(&dir, &["#universe: a,,b", "a,b"]);
+        let mut ri = ReadInput::new(vec!["program".to_string(), "-c".to_string(), path]).unwrap();
+        assert!(matches!(ri.read_file_subset(), Err(InputError::EmptyElement { location }) if location.line == Some(1) && location.column == Some(14)));
+    }
+
+    #[test]
+    fn test_get_partial() {
+        use set_covering_problem::entity::cover_mode::PartialTarget;
+
+        let partial = |value : &str| {
+            ReadInput::new(vec!["program".to_string(), "-partial".to_string(), value.to_string()])
+                .unwrap()
+                .get_partial()
+        };
+
+        assert!(matches!(partial("7"), Ok(Some(PartialTarget::Elements(7)))));
+        assert!(matches!(partial("0.5"), Ok(Some(PartialTarget::Fraction(f))) if f == 0.5));
+        assert!(matches!(partial("95%"), Ok(Some(PartialTarget::Fraction(f))) if (f - 0.95).abs() < 1e-12));
+        assert!(matches!(partial("150%"), Err(InputError::InvalidArgument { .. })));
+        assert!(matches!(partial("abc"), Err(InputError::InvalidArgument { .. })));
+    }
+
+    #[test]
+    fn test_read_file_demand_header() {
+        let dir = tempdir().unwrap();
+        let path = write_file(&dir, &["#demand: a=2, c = 3", "a,b", "c,a"]);
+        let mut ri = ReadInput::new(vec!["program".to_string(), "-c".to_string(), path]).unwrap();
+        ri.read_file_subset().unwrap();
+
+        assert_eq!(ri.get_demands(), &[("a".to_string(), 2), ("c".to_string(), 3)]);
+        assert_eq!(ri.demand_line, Some(1));
+
+        let dir = tempdir().unwrap();
+        let path = write_file(&dir, &["#demand: a=dos", "a,b"]);
+        let mut ri = ReadInput::new(vec!["program".to_string(), "-c".to_string(), path]).unwrap();
+        assert!(matches!(ri.read_file_subset(), Err(InputError::InvalidHeader { location, .. }) if location.line == Some(1)));
+    }
+
+    #[test]
+    fn test_get_mode_request() {
+        use set_covering_problem::entity::cover_mode::ModeRequest;
+
+        let request = |args : &[&str]| {
+            let mut all = vec!["program".to_string()];
+            all.extend(args.iter().map(|a| a.to_string()));
+            ReadInput::new(all).unwrap().get_mode_request()
+        };
+
+        assert!(matches!(request(&["-v"]), Ok(ModeRequest::Full)));
+        assert!(matches!(request(&["-multicover"]), Ok(ModeRequest::MultiCover)));
+        assert!(matches!(request(&["-partial", "3"]), Ok(ModeRequest::Partial(_))));
+        assert!(matches!(request(&["-partition"]), Ok(ModeRequest::Partition)));
+        assert!(matches!(request(&["-partial", "3", "-multicover"]), Err(InputError::InvalidArgument { .. })));
+        assert!(matches!(request(&["-partition", "-multicover"]), Err(InputError::InvalidArgument { .. })));
+    }
+
+    #[test]
+    fn test_read_file_cost_header_and_budget() {
+        use set_covering_problem::entity::cover_mode::ModeRequest;
+        use set_covering_problem::entity::max_coverage::Budget;
+
+        let dir = tempdir().unwrap();
+        let path = write_file(&dir, &["#cost: 0=2.5, 1=1", "a,b", "c"]);
+        let mut ri = ReadInput::new(vec!["program".to_string(), "-c".to_string(), path, "-budget-cost".to_string(), "3".to_string()]).unwrap();
+        ri.read_file_subset().unwrap();
+
+        assert_eq!(ri.get_subset_costs(), &[(0, 2.5), (1, 1.0)]);
+        assert!(matches!(ri.get_mode_request(), Ok(ModeRequest::MaxCoverage(Budget::Cost(c))) if c == 3.0));
+
+        let dir = tempdir().unwrap();
+        let path = write_file(&dir, &["#cost: 0=-1", "a,b"]);
+        let mut ri = ReadInput::new(vec!["program".to_string(), "-c".to_string(), path]).unwrap();
+        assert!(matches!(ri.read_file_subset(), Err(InputError::InvalidHeader { location, .. }) if location.line == Some(1)));
+
+        let ri = ReadInput::new(vec!["program".to_string(), "-budget".to_string(), "2".to_string()]).unwrap();
+        assert!(matches!(ri.get_budget(), Ok(Some(Budget::Subsets(2)))));
+    }
+
+    #[test]
+    fn test_read_file_weight_header() {
+        let dir = tempdir().unwrap();
+        let path = write_file(&dir, &["#universe: a,b,c", "#weight: a=2, c=0.5", "a,b", "c"]);
+        let mut ri = ReadInput::new(vec!["program".to_string(), "-c".to_string(), path]).unwrap();
+        ri.read_file_subset().unwrap();
+
+        assert_eq!(ri.get_element_weights(), &[("a".to_string(), 2.0), ("c".to_string(), 0.5)]);
+        assert_eq!(ri.weight_line, Some(2));
+
+        let dir = tempdir().unwrap();
+        let path = write_file(&dir, &["#weight: a=pesado", "a,b"]);
+        let mut ri = ReadInput::new(vec!["program".to_string(), "-c".to_string(), path]).unwrap();
+        assert!(matches!(ri.read_file_subset(), Err(InputError::InvalidHeader { location, .. }) if location.line == Some(1)));
+    }
+
+    #[test]
+    fn test_get_objective() {
+        let ri = ReadInput::new(vec!["program".to_string(), "-v".to_string()]).unwrap();
+        assert_eq!(ri.get_objective().unwrap().name(), "default");
+
+        let ri = ReadInput::new(vec!["program".to_string(), "-objective".to_string(), "squared".to_string()]).unwrap();
+        assert_eq!(ri.get_objective().unwrap().name(), "squared");
+
+        let ri = ReadInput::new(vec!["program".to_string(), "-objective".to_string(), "cubic".to_string()]).unwrap();
+        assert!(matches!(ri.get_objective(), Err(InputError::InvalidArgument { .. })));
+    }
+
+    #[test]
+    fn test_get_element_order() {
+        use set_covering_problem::entity::set::ElementOrder;
+
+        let ri = ReadInput::new(vec!["program".to_string(), "-v".to_string()]).unwrap();
+        assert_eq!(ri.get_element_order().unwrap(), ElementOrder::FirstSeen);
+
+        let ri = ReadInput::new(vec!["program".to_string(), "-order".to_string(), "natural".to_string()]).unwrap();
+        assert_eq!(ri.get_element_order().unwrap(), ElementOrder::Natural);
+
+        let ri = ReadInput::new(vec!["program".to_string(), "-order".to_string(), "random".to_string()]).unwrap();
+        assert!(matches!(ri.get_element_order(), Err(InputError::InvalidArgument { .. })));
+    }
+
+    #[test]
+    fn test_get_solver() {
+        use set_covering_problem::solver::registry::SolverRegistry;
+
+        let registry = SolverRegistry::default();
+        let ri = ReadInput::new(vec!["program".to_string(), "-v".to_string()]).unwrap();
+        assert_eq!(ri.get_solver(&registry).unwrap().name(), "rfd");
+
+        let ri = ReadInput::new(vec!["program".to_string(), "-solver".to_string(), "greedy".to_string()]).unwrap();
+        assert_eq!(ri.get_solver(&registry).unwrap().name(), "greedy");
+
+        let ri = ReadInput::new(vec!["program".to_string(), "-solver".to_string(), "tabu".to_string()]).unwrap();
+        assert!(matches!(ri.get_solver(&registry), Err(InputError::InvalidArgument { .. })));
+    }
+
+    #[test]
+    fn test_get_termination() {
+        use std::time::Duration;
+        use set_covering_problem::solver::termination::Termination;
+
+        let ri = ReadInput::new(vec!["program".to_string(), "-v".to_string()]).unwrap();
+        assert_eq!(ri.get_termination().unwrap(), Termination::none());
+        assert_eq!(ri.get_termination().unwrap().bounded(), Termination::default());
+
+        let args: Vec<String> = ["program", "-time", "1.5", "-stagnation", "20", "-target", "3", "-lower-bound", "2.5"]
+            .iter().map(|arg| arg.to_string()).collect();
+        let termination = ReadInput::new(args).unwrap().get_termination().unwrap();
+        assert_eq!(termination.time_limit, Some(Duration::from_millis(1500)));
+        assert_eq!(termination.max_iterations, None);
+        assert_eq!(termination.bounded(), termination);
+        assert_eq!(termination.max_stagnation, Some(20));
+        assert_eq!(termination.target_cost, Some(3.0));
+        assert_eq!(termination.lower_bound, Some(2.5));
+
+        for bad in [["-iterations", "0"], ["-time", "-1"], ["-target", "abc"]] {
+            let args = vec!["program".to_string(), bad[0].to_string(), bad[1].to_string()];
+            assert!(matches!(ReadInput::new(args).unwrap().get_termination(), Err(InputError::InvalidArgument { .. })));
+        }
+    }
+
+    #[test]
+    fn test_get_checkpoint_and_resume() {
+        let args = |list : &[&str]| list.iter().map(|arg| arg.to_string()).collect::<Vec<String>>();
+
+        let ri = ReadInput::new(args(&["program", "-v"])).unwrap();
+        assert_eq!(ri.get_checkpoint().unwrap(), None);
+        assert_eq!(ri.get_resume().unwrap(), None);
+
+        let ri = ReadInput::new(args(&["program", "-checkpoint", "run.json"])).unwrap();
+        assert_eq!(ri.get_checkpoint().unwrap(), Some(("run.json".to_string(), 50)));
+
+        let ri = ReadInput::new(args(&["program", "-checkpoint", "run.json", "-checkpoint-every", "5", "--resume", "old.json"])).unwrap();
+        assert_eq!(ri.get_checkpoint().unwrap(), Some(("run.json".to_string(), 5)));
+        assert_eq!(ri.get_resume().unwrap(), Some("old.json".to_string()));
+
+        let ri = ReadInput::new(args(&["program", "-checkpoint-every", "5", "--resume"])).unwrap();
+        assert!(matches!(ri.get_checkpoint(), Err(InputError::InvalidArgument { .. })));
+        assert!(matches!(ri.get_resume(), Err(InputError::InvalidArgument { .. })));
+    }
+
+    #[test]
+    fn test_parse_solution() {
+        use set_covering_problem::utils::read_input::parse_solution;
+
+        let text = "# solución previa\nS3, S4\n 7 1 # resto\n";
+        assert_eq!(parse_solution(text.as_bytes()).unwrap(), vec![3, 4, 7, 1]);
+
+        let json = "{\"size\": 2, \"subsets\": [3, 4]}";
+        assert_eq!(parse_solution(json.as_bytes()).unwrap(), vec![3, 4]);
+
+        assert!(matches!(parse_solution("3\nS-1\n".as_bytes()),
+            Err(InputError::Parse { location, source : Some(_), .. }) if location.line == Some(2)));
+        assert!(matches!(parse_solution("{\"size\": 2}".as_bytes()), Err(InputError::Validation { .. })));
+        assert!(matches!(parse_solution("{\"subsets\": [1,\n".as_bytes()),
+            Err(InputError::Parse { location, .. }) if location.line == Some(2)));
+        assert!(matches!(parse_solution("# nada\n".as_bytes()), Err(InputError::EmptyFile { .. })));
+    }
+
+    #[test]
+    fn test_get_initial_solution() {
+        let dir = tempdir().unwrap();
+        let path = write_file(&dir, &["S0,S2"]);
+
+        let ri = ReadInput::new(vec!["program".to_string(), "-initial".to_string(), path]).unwrap();
+        assert_eq!(ri.get_initial_solution().unwrap(), Some(vec![0, 2]));
+
+        let ri = ReadInput::new(vec!["program".to_string(), "-initial".to_string(), "no_existe.txt".to_string()]).unwrap();
+        assert!(matches!(ri.get_initial_solution(), Err(InputError::FileNotFound { .. })));
+
+        let ri = ReadInput::new(vec!["program".to_string(), "-v".to_string()]).unwrap();
+        assert_eq!(ri.get_initial_solution().unwrap(), None);
+    }
+
+    #[test]
+    fn test_get_polish() {
+        use set_covering_problem::solver::local_search::Strategy;
+
+        let ri = ReadInput::new(vec!["program".to_string(), "-v".to_string()]).unwrap();
+        assert_eq!(ri.get_polish().unwrap(), None);
+
+        let ri = ReadInput::new(vec!["program".to_string(), "-polish".to_string(), "-v".to_string()]).unwrap();
+        assert_eq!(ri.get_polish().unwrap(), Some(Strategy::FirstImprovement));
+
+        let ri = ReadInput::new(vec!["program".to_string(), "-polish".to_string(), "best".to_string()]).unwrap();
+        assert_eq!(ri.get_polish().unwrap(), Some(Strategy::BestImprovement));
+
+        let ri = ReadInput::new(vec!["program".to_string(), "-polish".to_string(), "any".to_string()]).unwrap();
+        assert!(matches!(ri.get_polish(), Err(InputError::InvalidArgument { .. })));
+    }
+
+    #[test]
+    fn test_get_formats() {
+        use set_covering_problem::utils::instance_format::{CsvOptions, InstanceFormat};
+
+        let args = |list : &[&str]| list.iter().map(|arg| arg.to_string()).collect::<Vec<String>>();
+
+        let ri = ReadInput::new(args(&["program", "-v"])).unwrap();
+        assert_eq!(ri.get_input_format().unwrap(), InstanceFormat::Txt);
+        assert!(ri.get_convert().unwrap().is_none());
+
+        let ri = ReadInput::new(args(&["program", "-from", "orlib", "-convert", "out.txt"])).unwrap();
+        assert_eq!(ri.get_input_format().unwrap(), InstanceFormat::OrLibrary);
+        assert_eq!(ri.get_convert().unwrap(), Some(("out.txt".to_string(), InstanceFormat::Txt)));
+
+        let ri = ReadInput::new(args(&["program", "-convert", "out.txt", "-to", "orlib"])).unwrap();
+        assert_eq!(ri.get_convert().unwrap(), Some(("out.txt".to_string(), InstanceFormat::OrLibrary)));
+
+        let ri = ReadInput::new(args(&["program", "-from", "json", "-convert", "out.txt", "-to", "json"])).unwrap();
+        assert_eq!(ri.get_input_format().unwrap(), InstanceFormat::Json);
+        assert_eq!(ri.get_convert().unwrap(), Some(("out.txt".to_string(), InstanceFormat::Json)));
+
+        let mut ri = ReadInput::new(args(&["program", "-c", "instancia.txt", "-from", "json"])).unwrap();
+        assert!(matches!(ri.load_set(), Err(InputError::InvalidPath { .. })));
+
+        let ri = ReadInput::new(args(&["program", "-from", "csv"])).unwrap();
+        assert_eq!(ri.get_input_format().unwrap(), InstanceFormat::Csv);
+        assert_eq!(ri.get_csv_options().unwrap(), CsvOptions::default());
+        assert!(ri.get_cost_table().unwrap().is_none());
+
+        let ri = ReadInput::new(args(&["program", "-delimiter", "tab", "-quote", "none", "-escape", "\\", "-header", "-costs", "c.csv"])).unwrap();
+        assert_eq!(ri.get_csv_options().unwrap(), CsvOptions { delimiter : b'\t', quote : None, escape : Some(b'\\'), header : true });
+        assert_eq!(ri.get_cost_table().unwrap(), Some("c.csv".to_string()));
+
+        let ri = ReadInput::new(args(&["program", "-delimiter", ";;", "-costs"])).unwrap();
+        assert!(matches!(ri.get_csv_options(), Err(InputError::InvalidArgument { .. })));
+        assert!(matches!(ri.get_cost_table(), Err(InputError::InvalidArgument { .. })));
+
+        let ri = ReadInput::new(args(&["program", "-from", "xml", "-convert"])).unwrap();
+        assert!(matches!(ri.get_input_format(), Err(InputError::InvalidArgument { .. })));
+        assert!(matches!(ri.get_convert(), Err(InputError::InvalidArgument { .. })));
+    }
+
+    #[test]
+    fn test_load_set_streams_instance() {
+        let dir = tempdir().unwrap();
+        let path = write_file(&dir, &["#universe: 1,2,3,x", "#weight: x=2", "1,2", "", "3,x", "2,1"]);
+        let mut ri = ReadInput::new(vec!["program".to_string(), "-c".to_string(), path]).unwrap();
+        let set = ri.load_set().unwrap().build().unwrap();
+
+        assert_eq!(set.get_size_set(), 4);
+        assert_eq!(set.get_size_subsets(), 3);
+        assert!(ri.subset.is_empty(), "La lectura en streaming no guarda los subconjuntos");
+        assert_eq!(ri.get_subset_line(1), Some(5));
+        assert_eq!(ri.get_element_weights(), &[("x".to_string(), 2.0)]);
+        assert_eq!(ri.get_warnings(), &[
+            InputWarning::BlankLine { line: 4 },
+            InputWarning::DuplicateSubset { line: 6, first_line: 3 },
+        ]);
+    }
+
+    #[test]
+    fn test_load_set_reads_gzip() {
+        use flate2::Compression;
+        use flate2::write::GzEncoder;
+
+        let dir = tempdir().unwrap();
+        let file_path = dir.path().join("test.txt.gz");
+        let mut encoder = GzEncoder::new(File::create(&file_path).unwrap(), Compression::default());
+        writeln!(encoder, "1,2\n2,3\n4").unwrap();
+        encoder.finish().unwrap();
+
+        let path = file_path.to_str().unwrap().to_string();
+        let mut ri = ReadInput::new(vec!["program".to_string(), "-c".to_string(), path]).unwrap();
+        assert_eq!(ri.read_file_subset().unwrap(), vec![
+            vec!["1".to_string(), "2".to_string()],
+            vec!["2".to_string(), "3".to_string()],
+            vec!["4".to_string()],
+        ]);
+
+        let set = ri.load_set().unwrap().build().unwrap();
+        assert!(set.labels.is_numeric());
+        assert!(set.is_cover_valid(&[0, 1, 2]));
+    }
+
+    #[test]
+    fn test_set_error_location() {
+        let dir = tempdir().unwrap();
+        let path = write_file(&dir, &["#cost: 0=1,7=2", "1,2", "3"]);
+        let mut ri = ReadInput::new(vec!["program".to_string(), "-c".to_string(), path.clone()]).unwrap();
+        let mut set = ri.load_set().unwrap().build().unwrap();
+        let error = ri.set_error(set.set_subset_costs(ri.get_subset_costs()).unwrap_err());
+
+        assert_eq!(error.location().and_then(|location| location.line), Some(1));
+        assert!(error.source().is_some());
+        assert!(error.to_string().starts_with(&format!("{}, línea 1: Instancia inválida", path)));
+    }
+
+    #[test]
+    fn test_format_errors_name_the_file() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("instancia.txt");
+        std::fs::write(&path, "2 2\n1 1\n1 x\n").unwrap();
+        let path = path.to_str().unwrap().to_string();
+        let mut ri = ReadInput::new(vec!["program".to_string(), "-c".to_string(), path.clone(), "-from".to_string(), "orlib".to_string()]).unwrap();
+
+        let error = ri.load_set().unwrap_err();
+        assert!(matches!(&error, InputError::Parse { location, source : Some(_), .. }
+            if location.line == Some(3) && location.path.as_deref() == Some(Path::new(&path))));
+
+        let costs = dir.path().join("costs.csv");
+        let edges = dir.path().join("edges.csv");
+        std::fs::write(&edges, "r1,a\n").unwrap();
+        std::fs::write(&costs, "r1,1\nr2,1\n").unwrap();
+        let mut ri = ReadInput::new(vec![
+            "program".to_string(), "-c".to_string(), edges.to_str().unwrap().to_string(),
+            "-from".to_string(), "csv".to_string(), "-costs".to_string(), costs.to_str().unwrap().to_string()
+        ]).unwrap();
+        assert!(matches!(ri.load_set(), Err(InputError::Validation { location, .. })
+            if location.line == Some(2) && location.path.as_deref() == Some(costs.as_path())));
+    }
+}
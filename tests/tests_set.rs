@@ -1,6 +1,7 @@
 #[cfg(test)]
 mod test {
-    use set_covering_problem::entity::set::Set; 
+    use set_covering_problem::entity::set::{ElementOrder, Set, SetError};
+    use set_covering_problem::utils::natural_order::natural_cmp;
 
     // Estructura auxiliar para crear un Set de prueba estándar.
     fn setup_test_set() -> Set {
@@ -50,7 +51,9 @@ mod test {
         assert_eq!(s2_elements.len(), 1, "S2 debe tener 1 elemento");
 
         // Índice inválido
-        assert!(set.get_elements_in_subset(5).is_err(), "Índice fuera de rango debe fallar");
+        assert_eq!(set.get_elements_in_subset(5).unwrap_err(), SetError::UnknownSubset(5));
+        assert_eq!(set.check_subset_indices(&[0, 7]).unwrap_err(), SetError::UnknownSubset(7));
+        assert_eq!(set.union_subset(&[1, 9]).unwrap_err(), SetError::UnknownSubset(9));
     }
 
     #[test]
@@ -67,7 +70,29 @@ mod test {
         let union_result_2 = set.union_subset(&subsets_union_2).unwrap();
         assert_eq!(union_result_2.len(), 3, "La unión debe contener 3 elementos únicos (a,b,c)");
     }
-    
+
+    #[test]
+    fn test_union_indices_and_bitmap() {
+        let set = setup_test_set();
+
+        // S3={a, c} y S1={c, d}: el resultado sale ordenado por índice sin importar el orden de entrada
+        assert_eq!(set.union_indices(&[3, 1]).unwrap(), vec![0, 2, 3]);
+        assert_eq!(set.union_subset(&[3, 1]).unwrap(), vec!["a".to_string(), "c".to_string(), "d".to_string()]);
+        assert_eq!(set.coverage_bitmap(&[3, 1]).unwrap(), vec![true, false, true, true, false]);
+        assert_eq!(set.union_size(&[0, 3]).unwrap(), 3);
+        assert_eq!(set.get_element_name(4).as_deref(), Some("e"));
+        assert!(set.union_indices(&[0, 5]).is_err());
+
+        // El buffer se reutiliza y se limpia entre llamadas
+        let mut buffer = vec![7; 2];
+        set.coverage_counts_into(&[2, 4], &mut buffer).unwrap();
+        assert_eq!(buffer, vec![1, 1, 1, 1, 2]);
+        set.add_coverage(3, &mut buffer);
+        assert_eq!(buffer, set.coverage_counts(&[2, 4, 3]).unwrap());
+        set.remove_coverage(4, &mut buffer);
+        assert_eq!(buffer, set.coverage_counts(&[2, 3]).unwrap());
+    }
+
     // ====================================================================
     // 3. Pruebas de Cobertura y Costo (is_cover_valid y calculate_cost)
     // ====================================================================
@@ -96,7 +121,7 @@ mod test {
     #[test]
     fn test_count_disjoint_subsets_fully_isolated() {
         let set = setup_test_set();
-        println!("{:?}",set.elements);
+        println!("{:?}",set.labels);
 
         // Familia F1 = {S0, S1, S2}
         // S0={a,b}, S1={c,d}, S2={e}. Todos son disjuntos de los otros dos.
@@ -139,4 +164,194 @@ mod test {
         let family_one = vec![0];
         assert_eq!(set.count_disjoint_subsets(&family_one), 0, "Familia con un elemento debe retornar 0");
     }
-}
\ No newline at end of file
+
+    // ====================================================================
+    // 5. Pruebas de Validación (try_new y with_universe)
+    // ====================================================================
+
+    fn to_subsets(data : &[&[&str]]) -> Vec<Vec<String>> {
+        data.iter()
+            .map(|subset| subset.iter().map(|e| e.to_string()).collect())
+            .collect()
+    }
+
+    #[test]
+    fn test_try_new_rejects_degenerate_input() {
+        assert!(Set::try_new(to_subsets(&[&["a", "b"], &["c"]])).is_ok());
+
+        assert_eq!(Set::try_new(vec![]).unwrap_err(), SetError::NoSubsets);
+        assert_eq!(
+            Set::try_new(to_subsets(&[&["a"], &[]])).unwrap_err(),
+            SetError::EmptySubset { subset: 1 }
+        );
+        assert_eq!(
+            Set::try_new(to_subsets(&[&["a", ""]])).unwrap_err(),
+            SetError::EmptyElement { subset: 0 }
+        );
+    }
+
+    #[test]
+    fn test_with_universe() {
+        let subsets = to_subsets(&[&["a", "b"], &["c"]]);
+        let universe: Vec<String> = vec!["a".to_string(), "b".to_string(), "c".to_string()];
+        let set = Set::with_universe(universe, subsets.clone()).unwrap();
+        assert_eq!(set.get_size_set(), 3);
+
+        // "d" pertenece al universo pero ningún subconjunto lo cubre
+        let universe: Vec<String> = vec!["a".to_string(), "b".to_string(), "c".to_string(), "d".to_string()];
+        assert_eq!(
+            Set::with_universe(universe, subsets.clone()).unwrap_err(),
+            SetError::UncoverableElements(vec!["d".to_string()])
+        );
+
+        // "c" aparece en un subconjunto pero no en el universo
+        let universe: Vec<String> = vec!["a".to_string(), "b".to_string()];
+        assert_eq!(
+            Set::with_universe(universe, subsets).unwrap_err(),
+            SetError::UnknownElement { subset: 1, element: "c".to_string() }
+        );
+    }
+
+    #[test]
+    fn test_from_universe_keeps_uncoverable_elements() {
+        let subsets = to_subsets(&[&["a", "b"], &["c"]]);
+        let universe: Vec<String> = vec!["d".to_string(), "a".to_string(), "b".to_string(), "c".to_string()];
+        let set = Set::from_universe(universe, subsets).unwrap();
+
+        assert_eq!(set.get_size_set(), 4, "El universo declarado define |X|");
+        assert_eq!(set.uncoverable_elements(), vec!["d".to_string()]);
+        assert!(!set.is_cover_valid(&[0, 1]), "Ninguna cobertura puede incluir a d");
+        assert_eq!(
+            set.check_coverable().unwrap_err(),
+            SetError::UncoverableElements(vec!["d".to_string()])
+        );
+    }
+
+    #[test]
+    fn test_element_order_policies() {
+        let subsets = to_subsets(&[&["10", "2"], &["1", "x"], &["2", "a10", "a9"]]);
+
+        let set = Set::new(subsets.clone());
+        assert_eq!(set.labels.to_strings(), vec!["10", "2", "1", "x", "a10", "a9"]);
+        assert_eq!(set.get_elements(), vec!["1", "2", "10", "a9", "a10", "x"]);
+
+        let mut lexicographic = Set::new(subsets.clone());
+        lexicographic.reorder_elements(ElementOrder::Lexicographic);
+        assert_eq!(lexicographic.labels.to_strings(), vec!["1", "10", "2", "a10", "a9", "x"]);
+
+        let mut natural = Set::new(subsets);
+        natural.set_element_weights(&[("x".to_string(), 3.0)]).unwrap();
+        natural.reorder_elements(ElementOrder::Natural);
+        assert_eq!(natural.labels.to_strings(), vec!["1", "2", "10", "a9", "a10", "x"]);
+        assert_eq!(natural.get_element_index("10"), Some(2));
+        assert_eq!(natural.get_element_weight(5), 3.0);
+        // S1 = {1, x} queda en los índices 0 y 5
+        assert_eq!(natural.get_elements_in_subset(1).unwrap(), vec![0, 5]);
+        assert_eq!(natural.union_subset(&[0]).unwrap(), vec!["2".to_string(), "10".to_string()]);
+    }
+
+    #[test]
+    fn test_construction_is_deterministic() {
+        let subsets = to_subsets(&[&["b", "a"], &["c", "d", "a"], &["e"]]);
+        let first = Set::new(subsets.clone());
+        let second = Set::new(subsets);
+
+        assert_eq!(first, second);
+        assert_eq!(format!("{:?}", first), format!("{:?}", second));
+        assert_eq!(format!("{}", first), format!("{}", second));
+    }
+
+    #[test]
+    fn test_natural_cmp() {
+        use std::cmp::Ordering;
+
+        assert_eq!(natural_cmp("2", "10"), Ordering::Less);
+        assert_eq!(natural_cmp("s10", "s9"), Ordering::Greater);
+        assert_eq!(natural_cmp("a", "a1"), Ordering::Less);
+        assert_eq!(natural_cmp("01", "1"), Ordering::Less);
+        assert_eq!(natural_cmp("x2y", "x2y"), Ordering::Equal);
+    }
+
+    #[test]
+    fn test_from_indices() {
+        let set = Set::from_indices(4, vec![vec![0, 1], vec![2, 3], vec![1, 1, 2]]).unwrap();
+
+        assert!(set.labels.is_numeric());
+        assert_eq!(set.get_size_set(), 4);
+        assert_eq!(set.max_subset, 2, "Los índices repetidos cuentan una vez");
+        assert!(set.is_cover_valid(&[0, 1]));
+        assert_eq!(set.get_element_index("3"), Some(3));
+        assert_eq!(set.union_subset(&[2]).unwrap(), vec!["1".to_string(), "2".to_string()]);
+
+        assert_eq!(
+            Set::from_indices(2, vec![vec![0], vec![2]]).unwrap_err(),
+            SetError::ElementOutOfRange { subset: 1, element: 2, size_set: 2 }
+        );
+        assert_eq!(Set::from_indices(2, vec![vec![0], vec![]]).unwrap_err(), SetError::EmptySubset { subset: 1 });
+    }
+
+    #[test]
+    fn test_from_ids_matches_named_set() {
+        let ids: Vec<Vec<u32>> = vec![vec![10, 2], vec![7], vec![2, 7, 30]];
+        let mut numeric = Set::from_ids(ids.clone()).unwrap();
+        let named = Set::new(ids.iter()
+            .map(|subset| subset.iter().map(|id| id.to_string()).collect())
+            .collect());
+
+        assert_eq!(numeric.rows, named.rows);
+        assert_eq!(numeric.columns, named.columns);
+        assert_eq!(numeric.get_elements(), named.get_elements());
+        assert_eq!(numeric.get_element_index("30"), Some(3));
+        assert_eq!(numeric.get_element_index("x"), None);
+
+        numeric.set_element_weights(&[("7".to_string(), 2.0)]).unwrap();
+        numeric.reorder_elements(ElementOrder::Natural);
+        assert_eq!(numeric.labels.to_strings(), vec!["2", "7", "10", "30"]);
+        assert_eq!(numeric.get_element_weight(1), 2.0);
+    }
+
+    #[test]
+    fn test_sparse_incidence_views() {
+        let set = setup_test_set();
+
+        // S3 = {a, c}; a lo cubren S0, S3 y S4; e lo cubren S2 y S4
+        assert_eq!(set.elements_of(3), &[0, 2]);
+        assert_eq!(set.subsets_covering(0), &[0, 3, 4]);
+        assert_eq!(set.subsets_covering(4), &[2, 4]);
+        assert_eq!(set.rows.non_zero(), set.columns.non_zero());
+
+        for subset in 0..set.get_size_subsets() {
+            for &element in set.elements_of(subset) {
+                assert!(set.subsets_covering(element).contains(&subset));
+            }
+        }
+
+        let mut reordered = Set::new(to_subsets(&[&["b", "a"], &["c", "a"]]));
+        reordered.reorder_elements(ElementOrder::Lexicographic);
+        assert_eq!(reordered.elements_of(1), &[0, 2]);
+        assert_eq!(reordered.subsets_covering(0), &[0, 1]);
+    }
+
+    #[test]
+    fn test_subset_names() {
+        let mut set = setup_test_set();
+        assert!(!set.has_subset_names());
+        assert_eq!(set.get_subset_name(3), "S3");
+
+        set.set_subset_names(&[(0, "route-1".to_string()), (3, "route-17".to_string())]).unwrap();
+        assert!(set.has_subset_names());
+        assert_eq!(set.get_subset_name(3), "route-17");
+        assert_eq!(set.get_subset_name(1), "S1");
+        assert_eq!(set.get_subset_index("route-17"), Some(3));
+        assert_eq!(set.get_subset_index("route-2"), None);
+
+        // Renombrar un subconjunto con su propio nombre no es un duplicado
+        assert!(set.set_subset_names(&[(3, "route-17".to_string())]).is_ok());
+        assert_eq!(
+            set.set_subset_names(&[(4, "route-1".to_string())]),
+            Err(SetError::DuplicateSubsetName { name : "route-1".to_string(), first : 0, second : 4 })
+        );
+        assert_eq!(set.set_subset_names(&[(2, " ".to_string())]), Err(SetError::EmptySubsetName { subset : 2 }));
+        assert_eq!(set.set_subset_names(&[(9, "x".to_string())]), Err(SetError::UnknownSubset(9)));
+    }
+}
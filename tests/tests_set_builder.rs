@@ -0,0 +1,74 @@
+#[cfg(test)]
+mod test {
+    use set_covering_problem::entity::set::{Set, SetError};
+    use set_covering_problem::entity::set_builder::SetBuilder;
+
+    fn to_subsets(data : &[&[&str]]) -> Vec<Vec<String>> {
+        data.iter()
+            .map(|subset| subset.iter().map(|e| e.to_string()).collect())
+            .collect()
+    }
+
+    #[test]
+    fn test_builder_matches_set_new() {
+        let subsets = to_subsets(&[&["a", "b"], &["b", "c", "d"], &["e"]]);
+        let mut builder = SetBuilder::new();
+        for subset in subsets.iter() {
+            builder.add_subset(subset);
+        }
+
+        assert_eq!(builder.size_subsets(), 3);
+        assert_eq!(builder.build().unwrap(), Set::new(subsets));
+    }
+
+    #[test]
+    fn test_builder_switches_numeric_labels_to_names() {
+        let mut builder = SetBuilder::new();
+        builder.add_subset(&["1", "2"]);
+        assert!(builder.labels.is_numeric());
+
+        builder.add_subset(&["2", "x"]);
+        let set = builder.build().unwrap();
+        assert!(!set.labels.is_numeric());
+        assert_eq!(set.labels.to_strings(), vec!["1", "2", "x"]);
+        assert_eq!(set.get_elements_in_subset(1).unwrap(), vec![1, 2]);
+
+        let mut builder = SetBuilder::new();
+        builder.add_subset(&["10", "2"]);
+        let set = builder.build().unwrap();
+        assert!(set.labels.is_numeric());
+        assert_eq!(set.get_element_index("10"), Some(0));
+    }
+
+    #[test]
+    fn test_builder_dedups_interned_elements() {
+        let mut builder = SetBuilder::new();
+        builder.add_subset(&["3", "1", "3", "1"]);
+        builder.add_subset(&["1"]);
+        assert_eq!(builder.entries, vec![0, 1, 1]);
+        assert_eq!(builder.offsets, vec![0, 2, 3]);
+
+        let set = builder.build().unwrap();
+        assert!(set.labels.is_numeric());
+        assert_eq!(set.labels.to_strings(), vec!["3", "1"]);
+        assert_eq!(set.get_elements_in_subset(0).unwrap(), vec![0, 1]);
+    }
+
+    #[test]
+    fn test_builder_reports_first_error() {
+        assert_eq!(SetBuilder::new().build().unwrap_err(), SetError::NoSubsets);
+
+        let mut builder = SetBuilder::with_universe(&["a".to_string(), "b".to_string()]);
+        builder.add_subset(&["a"]);
+        builder.add_subset(&["b", "c"]);
+        builder.add_subset::<&str>(&[]);
+        assert_eq!(
+            builder.build().unwrap_err(),
+            SetError::UnknownElement { subset: 1, element: "c".to_string() }
+        );
+
+        let mut builder = SetBuilder::new();
+        builder.add_subset(&["a", ""]);
+        assert_eq!(builder.build().unwrap_err(), SetError::EmptyElement { subset: 0 });
+    }
+}
@@ -0,0 +1,223 @@
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+    use rand::RngCore;
+
+    use set_covering_problem::entity::cover_mode::CoverMode;
+    use set_covering_problem::entity::max_coverage::Budget;
+    use set_covering_problem::entity::objective::{CardinalityObjective, DefaultObjective};
+    use set_covering_problem::entity::set::Set;
+    use set_covering_problem::entity::subset_cover::SubsetCover;
+    use set_covering_problem::solver::greedy::GreedySolver;
+    use set_covering_problem::solver::registry::SolverRegistry;
+    use set_covering_problem::solver::termination::{StopReason, Termination};
+    use set_covering_problem::solver::{prune, NoopObserver, SolverConfig, SolverObserver, SolverResult};
+
+    fn to_subsets(data : &[&[&str]]) -> Vec<Vec<String>> {
+        data.iter()
+            .map(|subset| subset.iter().map(|e| e.to_string()).collect())
+            .collect()
+    }
+
+    // Universal Set U = {a, b, c, d, e, f}; el óptimo es {S3, S4}
+    fn setup_test_set() -> Set {
+        Set::new(to_subsets(&[
+            &["a", "b"],             // S0
+            &["c", "d"],             // S1
+            &["e", "f"],             // S2
+            &["a", "b", "c"],        // S3
+            &["d", "e", "f"],        // S4
+            &["b", "d"],             // S5
+        ]))
+    }
+
+    #[derive(Default)]
+    struct RecordingObserver {
+        iterations : usize,
+        improvements : Vec<usize>
+    }
+
+    impl SolverObserver for RecordingObserver {
+        fn on_iteration(&mut self, _iteration : usize, _best : &SubsetCover) {
+            self.iterations += 1;
+        }
+
+        fn on_new_best(&mut self, iteration : usize, _best : &SubsetCover) {
+            self.improvements.push(iteration);
+        }
+    }
+
+    fn run(name : &str, set : &Set, config : &SolverConfig, seed : u64) -> SolverResult {
+        let mut solver = SolverRegistry::default().create(name).unwrap();
+        let mut rng = StdRng::seed_from_u64(seed);
+        solver.solve(set, config, &mut rng, &mut NoopObserver)
+    }
+
+    #[test]
+    fn test_registry() {
+        let mut registry = SolverRegistry::default();
+        assert_eq!(registry.names(), vec!["greedy", "local", "rfd"]);
+        assert!(registry.create("tabu").is_none());
+
+        registry.register("mine", || Box::new(GreedySolver));
+        assert_eq!(registry.create("mine").unwrap().name(), "greedy");
+        assert_eq!(registry.names().len(), 4);
+    }
+
+    #[test]
+    fn test_greedy_finds_feasible_cover() {
+        let set = setup_test_set();
+        let result = run("greedy", &set, &SolverConfig::default(), 1);
+
+        assert!(result.stats.feasible);
+        assert!(set.is_cover_valid(&result.cover.elements));
+        assert_eq!(result.cover.elements, vec![3, 4]);
+        assert_eq!(result.stats.solver, "greedy");
+    }
+
+    #[test]
+    fn test_rfd_is_reproducible_and_reports_events() {
+        let set = setup_test_set();
+        let config = SolverConfig { termination : Termination::none().with_max_iterations(15), drops : 5, ..SolverConfig::default() };
+
+        let first = run("rfd", &set, &config, 7);
+        let second = run("rfd", &set, &config, 7);
+        assert_eq!(first.cover.elements, second.cover.elements);
+        assert_eq!(first.cover.elements, vec![3, 4]);
+        assert_eq!(first.stats.evaluations, 75);
+
+        let mut observer = RecordingObserver::default();
+        let mut rng = StdRng::seed_from_u64(7);
+        let mut solver = SolverRegistry::default().create("rfd").unwrap();
+        solver.solve(&set, &config, &mut rng as &mut dyn RngCore, &mut observer);
+        assert_eq!(observer.iterations, 15);
+        assert_eq!(observer.improvements.first(), Some(&0));
+    }
+
+    #[test]
+    fn test_solvers_respect_mode() {
+        let set = setup_test_set();
+        let config = SolverConfig::new(CoverMode::Partial(4), Arc::new(CardinalityObjective));
+
+        for name in ["greedy", "rfd"] {
+            let result = run(name, &set, &config, 3);
+            assert!(result.stats.feasible, "{} debe cubrir al menos 4 elementos", name);
+            assert!(result.cover.elements.len() <= 2);
+        }
+    }
+
+    #[test]
+    fn test_prune_removes_redundant_subsets() {
+        let set = setup_test_set();
+        let config = SolverConfig::default();
+
+        // S0 y S5 sobran una vez que están S3 y S4
+        assert_eq!(prune(&[0, 3, 4, 5], &set, &config), vec![3, 4]);
+        // Con cardinalidad no se quitan subconjuntos que dejan elementos sin cubrir
+        let config = SolverConfig::new(CoverMode::Full, Arc::new(CardinalityObjective));
+        assert_eq!(prune(&[3, 4], &set, &config), vec![3, 4]);
+    }
+
+    #[test]
+    fn test_termination_reason_is_recorded() {
+        let set = setup_test_set();
+        let base = SolverConfig { drops : 3, ..SolverConfig::default() };
+        let optimum = base.objective.cost(&[3, 4], &set, &base.mode);
+        let with = |termination : Termination| SolverConfig { termination, ..base.clone() };
+
+        let result = run("rfd", &set, &with(Termination::none().with_max_iterations(1000).with_target_cost(optimum)), 2);
+        assert_eq!(result.stats.stop_reason, StopReason::TargetCost);
+        assert!(result.stats.iterations < 1000);
+        assert!(result.cover.cost <= optimum);
+
+        let result = run("rfd", &set, &with(Termination::none().with_lower_bound(optimum)), 2);
+        assert_eq!(result.stats.stop_reason, StopReason::LowerBound);
+
+        let result = run("rfd", &set, &with(Termination::none().with_max_iterations(1000).with_max_stagnation(4)), 2);
+        assert_eq!(result.stats.stop_reason, StopReason::Stagnation);
+        assert_eq!(result.stats.iterations, result.stats.best_iteration + 1 + 4);
+
+        let result = run("rfd", &set, &with(Termination::none().with_time_limit(Duration::ZERO)), 2);
+        assert_eq!(result.stats.stop_reason, StopReason::TimeLimit);
+        assert_eq!(result.stats.iterations, 0);
+
+        let result = run("rfd", &set, &with(Termination::none().with_max_iterations(6)), 2);
+        assert_eq!(result.stats.stop_reason, StopReason::MaxIterations);
+        assert_eq!(result.stats.iterations, 6);
+
+        assert_eq!(run("greedy", &set, &base, 2).stats.stop_reason, StopReason::Completed);
+        let result = run("greedy", &set, &with(Termination::default().with_target_cost(optimum)), 2);
+        assert_eq!(result.stats.stop_reason, StopReason::TargetCost);
+    }
+
+    #[test]
+    fn test_warm_start_is_validated_and_repaired() {
+        let set = setup_test_set();
+        let config = SolverConfig::default();
+
+        assert!(config.warm_start(&[0, 9], &set).is_err());
+
+        let (cover, repaired) = config.warm_start(&[4, 3], &set).unwrap();
+        assert!(!repaired);
+        assert_eq!(cover.elements, vec![3, 4]);
+
+        let (cover, repaired) = config.warm_start(&[0], &set).unwrap();
+        assert!(repaired);
+        assert!(cover.is_feasible(&set));
+        assert!(cover.elements.contains(&0));
+    }
+
+    #[test]
+    fn test_solvers_start_from_initial_cover() {
+        let set = setup_test_set();
+        let initial = [0, 1, 2, 5];
+        let base = SolverConfig { termination : Termination::none().with_max_iterations(5), drops : 2, ..SolverConfig::default() };
+        let config = base.clone().with_initial(&initial, &set).unwrap();
+        let initial_cost = config.initial.as_ref().unwrap().cost;
+
+        for name in ["greedy", "rfd"] {
+            let mut observer = RecordingObserver::default();
+            let mut solver = SolverRegistry::default().create(name).unwrap();
+            let mut rng = StdRng::seed_from_u64(4);
+            let result = solver.solve(&set, &config, &mut rng, &mut observer);
+
+            assert!(result.stats.feasible);
+            assert!(result.cover.cost <= initial_cost, "{} empeoró la solución inicial", name);
+            assert_eq!(observer.improvements.first(), Some(&0));
+        }
+
+        // Greedy completa la solución inicial y quita lo que sobra en lugar de empezar de cero
+        let result = run("greedy", &set, &base.with_initial(&[0, 1], &set).unwrap(), 1);
+        assert!(result.stats.feasible);
+        assert!(result.cover.elements.contains(&0));
+    }
+
+    #[test]
+    fn test_initial_cover_is_reused_and_extended_under_budget() {
+        let set = Set::new(vec![
+            vec!["a".to_string(), "b".to_string(), "c".to_string(), "d".to_string()],   // S0
+            vec!["a".to_string(), "b".to_string(), "e".to_string()],                    // S1
+            vec!["c".to_string(), "d".to_string(), "f".to_string()],                    // S2
+            vec!["e".to_string(), "f".to_string(), "g".to_string()],                    // S3
+        ]);
+        let mode = CoverMode::MaxCoverage(Budget::Subsets(2));
+        let (cover, _) = SolverConfig::new(mode.clone(), Arc::new(CardinalityObjective)).warm_start(&[1], &set).unwrap();
+
+        // La cobertura ya reparada se vuelve a evaluar con la función objetivo de cada configuración
+        let config = SolverConfig::new(mode, Arc::new(DefaultObjective)).with_initial_cover(&cover, &set);
+        let initial = config.initial.as_ref().unwrap();
+        assert_eq!(initial.elements, vec![1]);
+        assert_eq!(initial.get_objective_name(), "default");
+        assert_eq!(initial.cost, config.objective.cost(&[1], &set, &config.mode));
+
+        // Sin solución inicial greedy elige S0 y S3; con ella parte de S1 y agrega S2
+        let mut rng = StdRng::seed_from_u64(1);
+        assert_eq!(GreedySolver::construct(&set, &config, &mut rng), vec![1, 2]);
+        let empty = SolverConfig { initial : None, ..config };
+        assert_eq!(GreedySolver::construct(&set, &empty, &mut rng), vec![0, 3]);
+    }
+}
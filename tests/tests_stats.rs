@@ -0,0 +1,135 @@
+#[cfg(test)]
+mod test {
+    use set_covering_problem::entity::set::Set;
+    use set_covering_problem::entity::stats::{CoverReport, SetStats, StatsFormat};
+
+    fn to_subsets(data : &[&[&str]]) -> Vec<Vec<String>> {
+        data.iter()
+            .map(|subset| subset.iter().map(|e| e.to_string()).collect())
+            .collect()
+    }
+
+    // Universal Set U = {a, b, c, d, e, f, g}
+    // S0 y S3 son duplicados, {f, g} forman una componente aparte.
+    fn setup_test_set() -> Set {
+        Set::new(to_subsets(&[
+            &["a", "b"],        // S0
+            &["b", "c", "d"],   // S1
+            &["e"],             // S2
+            &["a", "b"],        // S3
+            &["f", "g"],        // S4
+            &["d", "e"],        // S5
+        ]))
+    }
+
+    #[test]
+    fn test_density_and_distribution() {
+        let stats = SetStats::new(&setup_test_set());
+
+        assert_eq!(stats.size_set, 7);
+        assert_eq!(stats.size_subsets, 6);
+        // 12 entradas no nulas en una matriz de 6 x 7
+        assert!((stats.density - 12.0 / 42.0).abs() < 1e-12);
+        assert!((stats.mean_subset - 2.0).abs() < 1e-12);
+
+        assert_eq!(stats.subset_size_distribution.get(&1), Some(&1));
+        assert_eq!(stats.subset_size_distribution.get(&2), Some(&4));
+        assert_eq!(stats.subset_size_distribution.get(&3), Some(&1));
+    }
+
+    #[test]
+    fn test_element_frequency() {
+        let stats = SetStats::new(&setup_test_set());
+
+        let frequency_of = |name : &str| stats.element_frequency.iter()
+            .find(|e| e.element == name)
+            .map(|e| e.frequency)
+            .unwrap();
+
+        assert_eq!(frequency_of("a"), 2);
+        assert_eq!(frequency_of("b"), 3);
+        assert_eq!(frequency_of("g"), 1);
+        assert_eq!(stats.unique_elements, vec!["c".to_string(), "f".to_string(), "g".to_string()]);
+        assert_eq!(stats.frequency_distribution.get(&1), Some(&3));
+    }
+
+    #[test]
+    fn test_element_frequency_natural_order() {
+        let stats = SetStats::new(&Set::new(to_subsets(&[&["10", "2", "x1"], &["1", "x10", "x2"]])));
+
+        let elements: Vec<&str> = stats.element_frequency.iter().map(|e| e.element.as_str()).collect();
+        assert_eq!(elements, vec!["1", "2", "10", "x1", "x2", "x10"]);
+        assert_eq!(stats.unique_elements, vec!["1", "2", "10", "x1", "x2", "x10"]);
+    }
+
+    #[test]
+    fn test_duplicates_and_components() {
+        let stats = SetStats::new(&setup_test_set());
+
+        assert_eq!(stats.duplicate_subsets, vec![vec![0, 3]]);
+
+        // {a, b, c, d, e} con S0, S1, S2, S3, S5 y {f, g} con S4
+        assert_eq!(stats.components.len(), 2);
+        assert_eq!(stats.components[0].elements, 5);
+        assert_eq!(stats.components[0].subsets, vec![0, 1, 2, 3, 5]);
+        assert_eq!(stats.components[1].elements, 2);
+        assert_eq!(stats.components[1].subsets, vec![4]);
+    }
+
+    #[test]
+    fn test_render_formats() {
+        let stats = SetStats::new(&setup_test_set());
+
+        let table = stats.render(StatsFormat::Table);
+        assert!(table.contains("Componentes conexas"));
+
+        let json: serde_json::Value = serde_json::from_str(&stats.render(StatsFormat::Json)).unwrap();
+        assert_eq!(json["size_set"], 7);
+        assert_eq!(json["duplicate_subsets"][0][1], 3);
+    }
+
+    #[test]
+    fn test_cover_report_uncovered_weight() {
+        let mut set = setup_test_set();
+        set.set_element_weights(&[("f".to_string(), 2.5), ("g".to_string(), 4.0)]).unwrap();
+
+        // {S0, S1} cubre a, b, c, d; faltan e, f y g
+        let report = CoverReport::new(&[0, 1], &set);
+        assert_eq!(report.covered, 4);
+        assert_eq!(report.uncovered, 3);
+        assert_eq!(report.uncovered_weight, 7.5);
+        assert_eq!(report.covered_weight, 4.0);
+        assert_eq!(report.uncovered_elements, vec!["e".to_string(), "f".to_string(), "g".to_string()]);
+        assert!(report.render(StatsFormat::Table).contains("Peso sin cubrir"));
+        // S0 y S1 comparten b
+        assert_eq!(report.redundancy, 1);
+        assert_eq!(report.top_overlaps, vec![(0, 1, 1)]);
+        assert_eq!(SetStats::new(&set).total_weight, 11.5);
+    }
+
+    #[test]
+    fn test_cover_report_subset_names_and_metadata() {
+        let mut set = setup_test_set();
+        set.set_subset_names(&[(1, "route-17".to_string()), (5, "route-23".to_string())]).unwrap();
+        set.metadata.insert("origen".to_string(), serde_json::json!("rutas"));
+
+        // S1 y S5 comparten d; S2 no tiene nombre
+        let report = CoverReport::new(&[1, 2, 5], &set);
+        assert_eq!(report.subsets, vec![1, 2, 5]);
+        assert_eq!(report.subset_names, vec!["route-17".to_string(), "S2".to_string(), "route-23".to_string()]);
+
+        let table = report.render(StatsFormat::Table);
+        assert!(table.contains("Subconjuntos: route-17, S2, route-23"));
+        assert!(table.contains("route-17∩route-23=1"));
+        assert!(table.contains("|   origen: rutas"));
+
+        let json: serde_json::Value = serde_json::from_str(&report.to_json()).unwrap();
+        assert_eq!(json["subset_names"][0], "route-17");
+        assert_eq!(json["metadata"]["origen"], "rutas");
+
+        // Sin nombres ni metadatos el JSON no cambia
+        let plain: serde_json::Value = serde_json::from_str(&CoverReport::new(&[1], &setup_test_set()).to_json()).unwrap();
+        assert!(plain.get("subset_names").is_none());
+        assert!(plain.get("metadata").is_none());
+    }
+}
@@ -1,7 +1,7 @@
 #[cfg(test)]
 mod test {
     use set_covering_problem::entity::subset_cover::SubsetCover; 
-    use set_covering_problem::entity::set::Set; 
+    use set_covering_problem::entity::set::{Set, SetError};
 
     // Constantes para el Set de prueba estándar:
     // |X| = 5
@@ -100,7 +100,7 @@ mod test {
 
         // 1. Agregar S0. C={S0}. |C|=1. Unión={a,b}. |Union|=2.
         // Costo = (1 + (5 - 2) * 5 * 5) / 5 = (1 + 75) / 5 = 76 / 5 = 15.2
-        let cost_1 = cover.add_subset(0, &set);
+        let cost_1 = cover.add_subset(0, &set).unwrap();
         assert_eq!(cover.elements, vec![0], "Debe contener el índice 0");
         assert_eq!(cover.size, 1);
         assert_eq!(cost_1, 15.2);
@@ -108,7 +108,7 @@ mod test {
 
         // 2. Agregar S1. C={S0, S1}. |C|=2. Unión={a,b,c,d}. |Union|=4.
         // Costo = (2 + (5 - 4) * 5 * 5) / 5 = (2 + 25) / 5 = 27 / 5 = 5.4
-        let cost_2 = cover.add_subset(1, &set);
+        let cost_2 = cover.add_subset(1, &set).unwrap();
         assert_eq!(cover.elements, vec![0, 1], "Debe contener los índices 0 y 1 ordenados");
         assert_eq!(cover.size, 2);
         assert_eq!(cost_2, 5.4);
@@ -121,12 +121,53 @@ mod test {
         let mut cover = SubsetCover::new(set.clone());
 
         // 1. Agregar S0. Costo = 15.2
-        cover.add_subset(0, &set);
+        cover.add_subset(0, &set).unwrap();
 
         // 2. Intentar agregar S0 de nuevo. El costo y el estado no deben cambiar.
-        let cost_after_duplicate = cover.add_subset(0, &set);
+        let cost_after_duplicate = cover.add_subset(0, &set).unwrap();
         assert_eq!(cover.elements, vec![0], "No debe agregar el duplicado");
         assert_eq!(cover.size, 1);
         assert_eq!(cost_after_duplicate, 15.2, "El costo no debe cambiar");
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_unknown_subsets_are_errors() {
+        let set = setup_test_set();
+        let mut cover = SubsetCover::new(set.clone());
+
+        assert_eq!(cover.add_subset(5, &set), Err(SetError::UnknownSubset(5)));
+        assert_eq!(cover.set_subsets(vec![0, 9], &set), Err(SetError::UnknownSubset(9)));
+        assert!(cover.elements.is_empty(), "Un error no debe modificar la cobertura");
+
+        // El costo de una familia inválida es infinito en lugar de abortar
+        assert_eq!(SubsetCover::calculate_cost(&[0, 7], &set), f64::INFINITY);
+    }
+
+    #[test]
+    fn test_calculate_cost_with_uncoverable_universe() {
+        // X = {a, b, c, z}, z no es cubierto por ningún subconjunto.
+        let universe: Vec<String> = vec!["a".to_string(), "b".to_string(), "c".to_string(), "z".to_string()];
+        let subsets_data = vec![
+            vec!["a".to_string(), "b".to_string()],
+            vec!["c".to_string()],
+        ];
+        let set = Set::from_universe(universe, subsets_data).unwrap();
+
+        // Costo = (2 + (4 - 3) * 2 * 2) / 2 = 6 / 2 = 3.0
+        let cost = SubsetCover::calculate_cost(&[0, 1], &set);
+        assert_eq!(cost, 3.0, "El elemento z no cubierto debe penalizarse");
+    }
+
+    #[test]
+    fn test_calculate_cost_weighted_penalty() {
+        let mut set = setup_test_set();
+        // e pesa 3: dejarlo sin cubrir cuesta tres veces más que otro elemento
+        set.set_element_weights(&[("e".to_string(), 3.0)]).unwrap();
+
+        // C = {S0, S1}: falta e. Costo = (2 + 3 * 5 * 5) / 5 = 77 / 5 = 15.4
+        assert_eq!(SubsetCover::calculate_cost(&[0, 1], &set), 15.4);
+
+        // C = {S0, S2}: faltan c y d. Costo = (2 + 2 * 5 * 5) / 5 = 52 / 5 = 10.4
+        assert_eq!(SubsetCover::calculate_cost(&[0, 2], &set), 10.4);
+    }
+}